@@ -90,6 +90,7 @@ use crate::protocol::ReviewOutputEvent;
 use crate::protocol::SandboxPolicy;
 use crate::protocol::SessionConfiguredEvent;
 use crate::protocol::StreamErrorEvent;
+use crate::protocol::StreamRetry;
 use crate::protocol::Submission;
 use crate::protocol::TokenCountEvent;
 use crate::protocol::TokenUsage;
@@ -1013,11 +1014,17 @@ impl Session {
         self.send_event(event).await;
     }
 
-    async fn notify_stream_error(&self, sub_id: &str, message: impl Into<String>) {
+    async fn notify_stream_error(
+        &self,
+        sub_id: &str,
+        message: impl Into<String>,
+        retry: Option<StreamRetry>,
+    ) {
         let event = Event {
             id: sub_id.to_string(),
             msg: EventMsg::StreamError(StreamErrorEvent {
                 message: message.into(),
+                retry,
             }),
         };
         self.send_event(event).await;
@@ -2030,6 +2037,11 @@ async fn run_turn(
                     sess.notify_stream_error(
                         &sub_id,
                         format!("Re-connecting... {retries}/{max_retries}"),
+                        Some(StreamRetry {
+                            attempt: retries,
+                            max_attempts: max_retries,
+                            delay_ms: u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        }),
                     )
                     .await;
 
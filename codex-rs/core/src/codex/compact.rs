@@ -14,6 +14,7 @@ use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::InputItem;
 use crate::protocol::InputMessageKind;
+use crate::protocol::StreamRetry;
 use crate::protocol::TaskStartedEvent;
 use crate::protocol::TurnContextItem;
 use crate::state::TaskKind;
@@ -138,6 +139,11 @@ async fn run_compact_task_inner(
                     sess.notify_stream_error(
                         &sub_id,
                         format!("Re-connecting... {retries}/{max_retries}"),
+                        Some(StreamRetry {
+                            attempt: retries,
+                            max_attempts: max_retries,
+                            delay_ms: u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        }),
                     )
                     .await;
                     tokio::time::sleep(delay).await;
@@ -3,8 +3,12 @@ pub use crate::config_loader::load_config_as_toml;
 use crate::config_loader::load_config_layers_with_overrides;
 use crate::config_loader::merge_toml_values;
 use crate::config_profile::ConfigProfile;
+use crate::config_types::ComposerKeymap;
+use crate::config_types::DEFAULT_HIGHLIGHT_SHORTCUT_KEYS;
 use crate::config_types::DEFAULT_OTEL_ENVIRONMENT;
+use crate::config_types::FooterStyle;
 use crate::config_types::History;
+use crate::config_types::KeyHintModifiers;
 use crate::config_types::McpServerConfig;
 use crate::config_types::McpServerTransportConfig;
 use crate::config_types::Notice;
@@ -149,6 +153,27 @@ pub struct Config {
     /// and turn completions when not focused.
     pub tui_notifications: Notifications,
 
+    /// How much of the footer the TUI keeps on screen while idle.
+    pub tui_footer: FooterStyle,
+
+    /// Whether key hints are drawn in plain ASCII; `None` leaves it to the TUI
+    /// to detect.
+    pub tui_ascii_key_hints: Option<bool>,
+
+    /// How modifiers are written in key hints; `None` picks the platform
+    /// convention.
+    pub tui_key_hint_modifiers: Option<KeyHintModifiers>,
+
+    /// Whether the shortcut overlay draws its keys brighter than their labels.
+    pub tui_highlight_shortcut_keys: bool,
+
+    /// Which key hints the TUI shortcut overlay lists.
+    pub tui_keymap: ComposerKeymap,
+
+    /// Key that opens the transcript as written in the config; `None` keeps
+    /// the TUI's default.
+    pub tui_transcript_key: Option<String>,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -1336,6 +1361,16 @@ impl Config {
                 .as_ref()
                 .map(|t| t.notifications.clone())
                 .unwrap_or_default(),
+            tui_footer: cfg.tui.as_ref().map(|t| t.footer).unwrap_or_default(),
+            tui_ascii_key_hints: cfg.tui.as_ref().and_then(|t| t.ascii_key_hints),
+            tui_key_hint_modifiers: cfg.tui.as_ref().and_then(|t| t.key_hint_modifiers),
+            tui_highlight_shortcut_keys: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.highlight_shortcut_keys)
+                .unwrap_or(DEFAULT_HIGHLIGHT_SHORTCUT_KEYS),
+            tui_keymap: cfg.tui.as_ref().map(|t| t.keymap).unwrap_or_default(),
+            tui_transcript_key: cfg.tui.as_ref().and_then(|t| t.transcript_key.clone()),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1511,6 +1546,95 @@ persistence = "none"
         assert_eq!(tui.notifications, Notifications::Enabled(false));
     }
 
+    #[test]
+    fn tui_config_footer_style() {
+        let parsed = toml::from_str::<ConfigToml>("[tui]\n")
+            .expect("TUI config without footer should succeed");
+        let tui = parsed.tui.expect("config should include tui section");
+        assert_eq!(tui.footer, FooterStyle::Full);
+
+        for (value, expected) in [
+            ("full", FooterStyle::Full),
+            ("minimal", FooterStyle::Minimal),
+            ("hidden", FooterStyle::Hidden),
+        ] {
+            let parsed = toml::from_str::<ConfigToml>(&format!("[tui]\nfooter = \"{value}\"\n"))
+                .expect("footer style should parse");
+            let tui = parsed.tui.expect("config should include tui section");
+            assert_eq!(tui.footer, expected);
+        }
+
+        assert!(toml::from_str::<ConfigToml>("[tui]\nfooter = \"compact\"\n").is_err());
+    }
+
+    #[test]
+    fn tui_config_ascii_key_hints() {
+        let parsed = toml::from_str::<ConfigToml>("[tui]\n").expect("empty tui section");
+        assert_eq!(parsed.tui.expect("tui section").ascii_key_hints, None);
+
+        let parsed = toml::from_str::<ConfigToml>("[tui]\nascii_key_hints = true\n")
+            .expect("ascii_key_hints should parse");
+        assert_eq!(parsed.tui.expect("tui section").ascii_key_hints, Some(true));
+    }
+
+    #[test]
+    fn tui_config_key_hint_modifiers() {
+        for (value, expected) in [
+            ("textual", KeyHintModifiers::Textual),
+            ("symbolic", KeyHintModifiers::Symbolic),
+        ] {
+            let parsed =
+                toml::from_str::<ConfigToml>(&format!("[tui]\nkey_hint_modifiers = \"{value}\"\n"))
+                    .expect("key_hint_modifiers should parse");
+            let tui = parsed.tui.expect("config should include tui section");
+            assert_eq!(tui.key_hint_modifiers, Some(expected));
+        }
+    }
+
+    #[test]
+    fn tui_config_highlight_shortcut_keys() {
+        let parsed = toml::from_str::<ConfigToml>("[tui]\n").expect("empty tui section");
+        assert_eq!(
+            parsed.tui.expect("tui section").highlight_shortcut_keys,
+            None
+        );
+
+        let parsed = toml::from_str::<ConfigToml>("[tui]\nhighlight_shortcut_keys = false\n")
+            .expect("highlight_shortcut_keys should parse");
+        assert_eq!(
+            parsed.tui.expect("tui section").highlight_shortcut_keys,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn tui_config_keymap() {
+        let parsed = toml::from_str::<ConfigToml>("[tui]\n").expect("empty tui section");
+        assert_eq!(
+            parsed.tui.expect("tui section").keymap,
+            ComposerKeymap::Emacs
+        );
+
+        let parsed =
+            toml::from_str::<ConfigToml>("[tui]\nkeymap = \"vim\"\n").expect("keymap should parse");
+        assert_eq!(parsed.tui.expect("tui section").keymap, ComposerKeymap::Vim);
+
+        assert!(toml::from_str::<ConfigToml>("[tui]\nkeymap = \"helix\"\n").is_err());
+    }
+
+    #[test]
+    fn tui_config_transcript_key() {
+        let parsed = toml::from_str::<ConfigToml>("[tui]\n").expect("empty tui section");
+        assert_eq!(parsed.tui.expect("tui section").transcript_key, None);
+
+        let parsed = toml::from_str::<ConfigToml>("[tui]\ntranscript_key = \"ctrl+o\"\n")
+            .expect("transcript_key should parse");
+        assert_eq!(
+            parsed.tui.expect("tui section").transcript_key.as_deref(),
+            Some("ctrl+o")
+        );
+    }
+
     #[test]
     fn test_sandbox_config_parsing() {
         let sandbox_full_access = r#"
@@ -2627,6 +2751,12 @@ model_verbosity = "high"
                 notices: Default::default(),
                 disable_paste_burst: false,
                 tui_notifications: Default::default(),
+                tui_footer: Default::default(),
+                tui_ascii_key_hints: None,
+                tui_key_hint_modifiers: None,
+                tui_highlight_shortcut_keys: true,
+                tui_keymap: Default::default(),
+                tui_transcript_key: None,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -2694,6 +2824,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_footer: Default::default(),
+            tui_ascii_key_hints: None,
+            tui_key_hint_modifiers: None,
+            tui_highlight_shortcut_keys: true,
+            tui_keymap: Default::default(),
+            tui_transcript_key: None,
             otel: OtelConfig::default(),
         };
 
@@ -2776,6 +2912,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_footer: Default::default(),
+            tui_ascii_key_hints: None,
+            tui_key_hint_modifiers: None,
+            tui_highlight_shortcut_keys: true,
+            tui_keymap: Default::default(),
+            tui_transcript_key: None,
             otel: OtelConfig::default(),
         };
 
@@ -2844,6 +2986,12 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_footer: Default::default(),
+            tui_ascii_key_hints: None,
+            tui_key_hint_modifiers: None,
+            tui_highlight_shortcut_keys: true,
+            tui_keymap: Default::default(),
+            tui_transcript_key: None,
             otel: OtelConfig::default(),
         };
 
@@ -331,6 +331,10 @@ impl Default for Notifications {
     }
 }
 
+/// Whether the shortcut overlay draws its keys at full brightness when
+/// `tui.highlight_shortcut_keys` is unset.
+pub const DEFAULT_HIGHLIGHT_SHORTCUT_KEYS: bool = true;
+
 /// Collection of settings that are specific to the TUI.
 #[derive(Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct Tui {
@@ -338,6 +342,70 @@ pub struct Tui {
     /// Defaults to `false`.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// How much of the footer below the composer to keep on screen while idle.
+    /// Defaults to `full`.
+    #[serde(default)]
+    pub footer: FooterStyle,
+
+    /// Draw key hints in plain ASCII ("ctrl+c", "up") for fonts without the
+    /// arrow glyphs. Unset, it is picked from `TERM`.
+    #[serde(default)]
+    pub ascii_key_hints: Option<bool>,
+
+    /// Write modifiers in key hints as words ("ctrl + t") or macOS symbols
+    /// ("⌃T"). Unset, symbols are used on macOS.
+    #[serde(default)]
+    pub key_hint_modifiers: Option<KeyHintModifiers>,
+
+    /// Draw the keys in the shortcut overlay at full brightness so they stand
+    /// out from their dimmed descriptions. Defaults to `true`.
+    #[serde(default)]
+    pub highlight_shortcut_keys: Option<bool>,
+
+    /// Which key hints the shortcut overlay lists. Defaults to `emacs`.
+    #[serde(default)]
+    pub keymap: ComposerKeymap,
+
+    /// Key that opens the transcript, written like "ctrl+o". Defaults to
+    /// `ctrl+t`.
+    #[serde(default)]
+    pub transcript_key: Option<String>,
+}
+
+/// Family of key bindings the TUI shortcut overlay lists.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ComposerKeymap {
+    /// Readline-style chords such as `ctrl + j`.
+    #[default]
+    Emacs,
+    /// Adds the vim-style mode keys to the overlay.
+    Vim,
+}
+
+/// How modifier keys are written in the TUI's key hints.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyHintModifiers {
+    /// "ctrl + t", "alt + ↑".
+    Textual,
+    /// "⌃T", "⌥↑".
+    Symbolic,
+}
+
+/// Amount of footer the TUI shows below the composer. Transient hints such as
+/// the Ctrl+C reminder are shown regardless.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FooterStyle {
+    /// Context indicator and "? for shortcuts".
+    #[default]
+    Full,
+    /// A blank row in place of the shortcut summary.
+    Minimal,
+    /// No footer rows while idle, giving the composer the space.
+    Hidden,
 }
 
 /// Settings for notices we display to users via the tui and app-server clients
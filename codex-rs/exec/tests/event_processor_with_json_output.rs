@@ -451,6 +451,7 @@ fn stream_error_event_produces_error() {
         "e1",
         EventMsg::StreamError(codex_core::protocol::StreamErrorEvent {
             message: "retrying".to_string(),
+            retry: None,
         }),
     ));
     assert_eq!(
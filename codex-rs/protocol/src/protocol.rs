@@ -1202,6 +1202,19 @@ pub struct BackgroundEventEvent {
 #[derive(Debug, Clone, Deserialize, Serialize, TS)]
 pub struct StreamErrorEvent {
     pub message: String,
+    /// Present when the turn will be retried after the stream dropped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<StreamRetry>,
+}
+
+/// Progress of the automatic retries after a dropped stream.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, TS, PartialEq, Eq)]
+pub struct StreamRetry {
+    /// 1-based number of the upcoming attempt.
+    pub attempt: u64,
+    pub max_attempts: u64,
+    /// How long the turn waits before retrying, in milliseconds.
+    pub delay_ms: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, TS)]
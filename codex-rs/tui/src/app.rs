@@ -3,11 +3,14 @@ use crate::app_backtrack::BacktrackState;
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::ShortcutId;
 use crate::chatwidget::ChatWidget;
 use crate::diff_render::DiffSummary;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::file_search::FileSearchManager;
 use crate::history_cell::HistoryCell;
+use crate::key_hint;
+use crate::key_hint::KeyBinding;
 use crate::pager_overlay::Overlay;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::resume_picker::ResumeSelection;
@@ -61,6 +64,8 @@ pub(crate) struct App {
     pub(crate) file_search: FileSearchManager,
 
     pub(crate) transcript_cells: Vec<Arc<dyn HistoryCell>>,
+    /// Opens the transcript overlay, per the `tui.transcript_key` setting.
+    pub(crate) transcript_key: KeyBinding,
 
     // Pager overlay state (Transcript or Static like Diff)
     pub(crate) overlay: Option<Overlay>,
@@ -142,6 +147,7 @@ impl App {
         };
 
         let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
+        let transcript_key = key_hint::transcript_key(config.tui_transcript_key.as_deref());
 
         let mut app = Self {
             server: conversation_manager,
@@ -153,6 +159,7 @@ impl App {
             file_search,
             enhanced_keys_supported,
             transcript_cells: Vec::new(),
+            transcript_key,
             overlay: None,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
@@ -314,6 +321,17 @@ impl App {
                 ));
                 tui.frame_requester().schedule_frame();
             }
+            AppEvent::RunShortcut(id) => match id {
+                ShortcutId::ShowTranscript => self.open_transcript_overlay(tui),
+                // Esc esc: prime backtracking, then open the preview.
+                ShortcutId::EditPrevious => {
+                    if self.chat_widget.is_normal_backtrack_mode() {
+                        self.handle_backtrack_esc_key(tui);
+                        self.handle_backtrack_esc_key(tui);
+                    }
+                }
+                _ => self.chat_widget.run_shortcut(id),
+            },
             AppEvent::StartFileSearch(query) => {
                 if !query.is_empty() {
                     self.file_search.on_user_query(query);
@@ -443,16 +461,11 @@ impl App {
 
     async fn handle_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) {
         match key_event {
-            KeyEvent {
-                code: KeyCode::Char('t'),
-                modifiers: crossterm::event::KeyModifiers::CONTROL,
-                kind: KeyEventKind::Press,
-                ..
-            } => {
-                // Enter alternate screen and set viewport to full size.
-                let _ = tui.enter_alt_screen();
-                self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
-                tui.frame_requester().schedule_frame();
+            key_event
+                if key_event.kind == KeyEventKind::Press
+                    && self.transcript_key.is_press(key_event) =>
+            {
+                self.open_transcript_overlay(tui);
             }
             // Esc primes/advances backtracking only in normal (not working) mode
             // with the composer focused and empty. In any other state, forward
@@ -543,6 +556,7 @@ mod tests {
             active_profile: None,
             file_search,
             transcript_cells: Vec::new(),
+            transcript_key: key_hint::DEFAULT_TRANSCRIPT_KEY,
             overlay: None,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
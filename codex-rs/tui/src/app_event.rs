@@ -7,6 +7,7 @@ use codex_core::protocol::Event;
 use codex_file_search::FileMatch;
 
 use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::ShortcutId;
 use crate::history_cell::HistoryCell;
 
 use codex_core::protocol::AskForApproval;
@@ -44,6 +45,10 @@ pub(crate) enum AppEvent {
     /// Result of computing a `/diff` command.
     DiffResult(String),
 
+    /// Run the action of a shortcut picked from the shortcut overlay, as if
+    /// its keys had been pressed.
+    RunShortcut(ShortcutId),
+
     InsertHistoryCell(Box<dyn HistoryCell>),
 
     StartCommitAnimation,
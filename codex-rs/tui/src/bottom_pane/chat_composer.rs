@@ -19,13 +19,30 @@ use super::chat_composer_history::ChatComposerHistory;
 use super::command_popup::CommandItem;
 use super::command_popup::CommandPopup;
 use super::file_search_popup::FileSearchPopup;
+use super::footer::CONTEXT_CLEAR_PERCENT;
+use super::footer::CTRL_C_REMINDER_TIMEOUT;
+use super::footer::FooterLineCache;
 use super::footer::FooterMode;
 use super::footer::FooterProps;
+use super::footer::KeymapStyle;
+use super::footer::NotificationSeverity;
+use super::footer::OverlayMove;
+use super::footer::OverlayNavigation;
+use super::footer::ReconnectState;
+use super::footer::SandboxBadge;
+use super::footer::ShortcutId;
+use super::footer::ShortcutTable;
+use super::footer::ShortcutsState;
+use super::footer::apply_footer_style;
+use super::footer::ctrl_c_reminder_expired;
 use super::footer::esc_hint_mode;
-use super::footer::footer_height;
-use super::footer::render_footer;
+use super::footer::next_countdown_tick;
+use super::footer::notification_expired;
+use super::footer::overlay_page_count;
+use super::footer::overlay_selection_page;
 use super::footer::reset_mode_after_activity;
 use super::footer::toggle_shortcut_mode;
+use super::footer::transition_to_idle;
 use super::paste_burst::CharDecision;
 use super::paste_burst::PasteBurst;
 use crate::bottom_pane::paste_burst::FlushResult;
@@ -38,6 +55,10 @@ use crate::bottom_pane::prompt_args::prompt_has_numeric_placeholders;
 use crate::slash_command::SlashCommand;
 use crate::slash_command::built_in_slash_commands;
 use crate::style::user_message_style;
+use codex_core::config_types::ComposerKeymap;
+use codex_core::config_types::DEFAULT_HIGHLIGHT_SHORTCUT_KEYS;
+use codex_core::config_types::FooterStyle;
+use codex_core::protocol_config_types::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::custom_prompts::CustomPrompt;
 use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
 
@@ -45,11 +66,15 @@ use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::textarea::TextArea;
 use crate::bottom_pane::textarea::TextAreaState;
+use crate::clipboard_paste::CLIPBOARD_IMAGES_SUPPORTED;
 use crate::clipboard_paste::normalize_pasted_path;
 use crate::clipboard_paste::pasted_image_format;
 use crate::history_cell;
+use crate::ui_consts::IDLE_TIMEOUT_SECS;
 use crate::ui_consts::LIVE_PREFIX_COLS;
 use codex_file_search::FileMatch;
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
@@ -61,6 +86,9 @@ use std::time::Instant;
 /// placeholder in the UI.
 const LARGE_PASTE_CHAR_THRESHOLD: usize = 1000;
 
+/// Sent when enter resumes a paused turn with nothing typed.
+const RESUME_PROMPT: &str = "continue";
+
 /// Result returned when the user interacts with the text area.
 #[derive(Debug, PartialEq)]
 pub enum InputResult {
@@ -101,6 +129,8 @@ pub(crate) struct ChatComposer {
     attached_images: Vec<AttachedImage>,
     placeholder_text: String,
     is_task_running: bool,
+    esc_interrupts: bool,
+    has_previous_message: bool,
     // Non-bracketed paste burst tracker.
     paste_burst: PasteBurst,
     // When true, disables paste-burst logic and inserts characters immediately.
@@ -108,7 +138,27 @@ pub(crate) struct ChatComposer {
     custom_prompts: Vec<CustomPrompt>,
     footer_mode: FooterMode,
     footer_hint_override: Option<Vec<(String, String)>>,
+    sandbox_badge: Option<SandboxBadge>,
     context_window_percent: Option<u8>,
+    context_tokens_used: Option<u64>,
+    context_tokens_max: Option<u64>,
+    tokens_per_second: Option<f32>,
+    ctrl_c_reminder_armed_at: Option<Instant>,
+    awaiting_approval: bool,
+    reconnecting: Option<ReconnectState>,
+    active_profile: Option<String>,
+    last_activity: Instant,
+    footer_style: FooterStyle,
+    highlight_shortcut_keys: bool,
+    keymap: KeymapStyle,
+    footer_cache: FooterLineCache,
+    /// Width the footer was last drawn at, to count shortcut overlay pages.
+    footer_width: Cell<Option<u16>>,
+    shortcut_table: ShortcutTable,
+    task_started_at: Option<Instant>,
+    model: Option<String>,
+    reasoning_effort: Option<ReasoningEffortConfig>,
+    queued_user_messages: usize,
 }
 
 /// Popup state – at most one can be visible at any time.
@@ -146,12 +196,33 @@ impl ChatComposer {
             attached_images: Vec::new(),
             placeholder_text,
             is_task_running: false,
+            esc_interrupts: false,
+            has_previous_message: false,
             paste_burst: PasteBurst::default(),
             disable_paste_burst: false,
             custom_prompts: Vec::new(),
             footer_mode: FooterMode::ShortcutSummary,
             footer_hint_override: None,
+            sandbox_badge: None,
             context_window_percent: None,
+            context_tokens_used: None,
+            context_tokens_max: None,
+            tokens_per_second: None,
+            ctrl_c_reminder_armed_at: None,
+            awaiting_approval: false,
+            reconnecting: None,
+            active_profile: None,
+            last_activity: Instant::now(),
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: DEFAULT_HIGHLIGHT_SHORTCUT_KEYS,
+            keymap: KeymapStyle::Emacs,
+            footer_cache: FooterLineCache::default(),
+            footer_width: Cell::new(None),
+            shortcut_table: ShortcutTable::default(),
+            task_started_at: None,
+            model: None,
+            reasoning_effort: None,
+            queued_user_messages: 0,
         };
         // Apply configuration via the setter to keep side-effects centralized.
         this.set_disable_paste_burst(disable_paste_burst);
@@ -162,7 +233,7 @@ impl ChatComposer {
         let footer_props = self.footer_props();
         let footer_hint_height = self
             .custom_footer_height()
-            .unwrap_or_else(|| footer_height(footer_props));
+            .unwrap_or_else(|| self.footer_cache.height(&footer_props, width));
         let footer_spacing = Self::footer_spacing(footer_hint_height);
         let footer_total_height = footer_hint_height + footer_spacing;
         const COLS_WITH_MARGIN: u16 = LIVE_PREFIX_COLS + 1;
@@ -180,7 +251,7 @@ impl ChatComposer {
         let footer_props = self.footer_props();
         let footer_hint_height = self
             .custom_footer_height()
-            .unwrap_or_else(|| footer_height(footer_props));
+            .unwrap_or_else(|| self.footer_cache.height(&footer_props, area.width));
         let footer_spacing = Self::footer_spacing(footer_hint_height);
         let footer_total_height = footer_hint_height + footer_spacing;
         let popup_constraint = match &self.active_popup {
@@ -248,6 +319,7 @@ impl ChatComposer {
     }
 
     pub fn handle_paste(&mut self, pasted: String) -> bool {
+        self.last_activity = Instant::now();
         let char_count = pasted.chars().count();
         if char_count > LARGE_PASTE_CHAR_THRESHOLD {
             let placeholder = format!("[Pasted Content {char_count} chars]");
@@ -377,9 +449,17 @@ impl ChatComposer {
     pub fn set_ctrl_c_quit_hint(&mut self, show: bool, has_focus: bool) {
         self.ctrl_c_quit_hint = show;
         if show {
-            self.footer_mode = FooterMode::CtrlCReminder;
+            // Keep the paused indicator underneath so it returns once the
+            // reminder expires; `footer_mode()` shows the reminder over it.
+            if self.footer_mode != FooterMode::Paused {
+                self.footer_mode = FooterMode::CtrlCReminder { expires_at: None };
+            }
+            self.ctrl_c_reminder_armed_at = Some(Instant::now());
         } else {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            if self.footer_mode != FooterMode::Paused {
+                self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
+            }
+            self.ctrl_c_reminder_armed_at = None;
         }
         self.set_has_focus(has_focus);
     }
@@ -392,6 +472,7 @@ impl ChatComposer {
 
     /// Handle a key event coming from the main UI.
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> (InputResult, bool) {
+        self.last_activity = Instant::now();
         let result = match &mut self.active_popup {
             ActivePopup::Command(_) => self.handle_key_event_with_slash_popup(key_event),
             ActivePopup::File(_) => self.handle_key_event_with_file_popup(key_event),
@@ -420,13 +501,17 @@ impl ChatComposer {
             return (InputResult::None, true);
         }
         if key_event.code == KeyCode::Esc {
-            let next_mode = esc_hint_mode(self.footer_mode, self.is_task_running);
+            let next_mode = esc_hint_mode(
+                self.footer_mode.clone(),
+                self.is_task_running,
+                self.esc_backtrack_hint,
+            );
             if next_mode != self.footer_mode {
                 self.footer_mode = next_mode;
                 return (InputResult::None, true);
             }
         } else {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
         }
         let ActivePopup::Command(popup) = &mut self.active_popup else {
             unreachable!();
@@ -584,13 +669,17 @@ impl ChatComposer {
             return (InputResult::None, true);
         }
         if key_event.code == KeyCode::Esc {
-            let next_mode = esc_hint_mode(self.footer_mode, self.is_task_running);
+            let next_mode = esc_hint_mode(
+                self.footer_mode.clone(),
+                self.is_task_running,
+                self.esc_backtrack_hint,
+            );
             if next_mode != self.footer_mode {
                 self.footer_mode = next_mode;
                 return (InputResult::None, true);
             }
         } else {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
         }
         let ActivePopup::File(popup) = &mut self.active_popup else {
             unreachable!();
@@ -856,16 +945,29 @@ impl ChatComposer {
         if self.handle_shortcut_overlay_key(&key_event) {
             return (InputResult::None, true);
         }
+        if self.footer_mode == FooterMode::Paused
+            && key_event.code == KeyCode::Enter
+            && key_event.modifiers == KeyModifiers::NONE
+            && self.is_empty()
+        {
+            self.footer_mode = reset_mode_after_activity(FooterMode::Paused);
+            self.history.record_local_submission(RESUME_PROMPT);
+            return (InputResult::Submitted(RESUME_PROMPT.to_string()), true);
+        }
         if key_event.code == KeyCode::Esc {
             if self.is_empty() {
-                let next_mode = esc_hint_mode(self.footer_mode, self.is_task_running);
+                let next_mode = esc_hint_mode(
+                    self.footer_mode.clone(),
+                    self.is_task_running,
+                    self.esc_backtrack_hint,
+                );
                 if next_mode != self.footer_mode {
                     self.footer_mode = next_mode;
                     return (InputResult::None, true);
                 }
             }
         } else {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
         }
         match key_event {
             KeyEvent {
@@ -877,6 +979,23 @@ impl ChatComposer {
                 self.app_event_tx.send(AppEvent::ExitRequest);
                 (InputResult::None, true)
             }
+            // With context nearly exhausted, ctrl + k on an empty composer runs
+            // the overlay's "start a new chat", which asks before dropping the
+            // conversation. Otherwise it keeps its kill-to-end-of-line meaning.
+            KeyEvent {
+                code: KeyCode::Char('k'),
+                modifiers: crossterm::event::KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.is_empty()
+                && self
+                    .context_window_percent
+                    .is_some_and(|percent| percent <= CONTEXT_CLEAR_PERCENT) =>
+            {
+                self.app_event_tx
+                    .send(AppEvent::RunShortcut(ShortcutId::ClearContext));
+                (InputResult::None, true)
+            }
             // -------------------------------------------------------------
             // History navigation (Up / Down) – only when the composer is not
             // empty or when the cursor is at the correct position, to avoid
@@ -1074,7 +1193,7 @@ impl ChatComposer {
         self.handle_paste_burst_flush(now);
 
         if !matches!(input.code, KeyCode::Esc) {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
         }
 
         // If we're capturing a burst and receive Enter, accumulate it instead of inserting.
@@ -1355,6 +1474,29 @@ impl ChatComposer {
             return false;
         }
 
+        // While the overlay is open, arrows pick an entry and enter runs it.
+        if let FooterMode::ShortcutOverlay(state) = self.footer_mode.clone()
+            && key_event.modifiers == KeyModifiers::NONE
+        {
+            let direction = match key_event.code {
+                KeyCode::Left => Some(OverlayMove::Left),
+                KeyCode::Right => Some(OverlayMove::Right),
+                KeyCode::Up => Some(OverlayMove::Up),
+                KeyCode::Down => Some(OverlayMove::Down),
+                _ => None,
+            };
+            if let Some(direction) = direction {
+                return self.move_overlay_selection(state, direction);
+            }
+            if key_event.code == KeyCode::Enter
+                && let Some(id) = state.selected
+            {
+                self.footer_mode = FooterMode::ShortcutSummary;
+                self.app_event_tx.send(AppEvent::RunShortcut(id));
+                return true;
+            }
+        }
+
         let toggles = matches!(
             key_event,
             KeyEvent {
@@ -1368,33 +1510,230 @@ impl ChatComposer {
             return false;
         }
 
-        let next = toggle_shortcut_mode(self.footer_mode, self.ctrl_c_quit_hint);
+        self.clear_expired_footer_notification();
+        // Before the first frame the width is unknown; treat the overlay as a
+        // single page.
+        let overlay_pages = self
+            .footer_width
+            .get()
+            .map_or(1, |width| overlay_page_count(&self.footer_props(), width));
+        let next = toggle_shortcut_mode(
+            self.footer_mode.clone(),
+            self.ctrl_c_reminder_active(Instant::now()),
+            self.shortcuts_state(),
+            overlay_pages,
+        );
         let changed = next != self.footer_mode;
         self.footer_mode = next;
         changed
     }
 
+    /// Moves the overlay selection and turns to the page that shows it.
+    /// Before the first frame the layout is unknown, so nothing moves.
+    fn move_overlay_selection(&mut self, state: ShortcutsState, direction: OverlayMove) -> bool {
+        let Some(width) = self.footer_width.get() else {
+            return false;
+        };
+        let props = self.footer_props();
+        let selected = OverlayNavigation::new(&props, width).step(state.selected, direction);
+        let mut next = ShortcutsState { selected, ..state };
+        let page = overlay_selection_page(
+            &FooterProps {
+                mode: FooterMode::ShortcutOverlay(next),
+                ..props
+            },
+            width,
+        );
+        next.page = page.unwrap_or(state.page);
+        self.footer_mode = FooterMode::ShortcutOverlay(next);
+        true
+    }
+
     fn footer_props(&self) -> FooterProps {
         FooterProps {
-            mode: self.footer_mode(),
-            esc_backtrack_hint: self.esc_backtrack_hint,
-            use_shift_enter_hint: self.use_shift_enter_hint,
+            mode: apply_footer_style(self.footer_mode(), self.footer_style),
             is_task_running: self.is_task_running,
+            sandbox_badge: self.sandbox_badge,
             context_window_percent: self.context_window_percent,
+            context_tokens_used: self.context_tokens_used,
+            context_tokens_max: self.context_tokens_max,
+            tokens_per_second: self.tokens_per_second,
+            model: self.model.clone(),
+            reasoning_effort: self.reasoning_effort,
+            queued_user_messages: self.queued_user_messages,
+            task_started_at: self.task_started_at,
+            active_profile: self.active_profile.clone(),
+            indent_cols: None,
+        }
+    }
+
+    /// The footer as the shortcut overlay, whatever mode it is in now.
+    pub(crate) fn shortcut_overlay_props(&self) -> FooterProps {
+        FooterProps {
+            mode: FooterMode::ShortcutOverlay(self.shortcuts_state()),
+            ..self.footer_props()
+        }
+    }
+
+    fn shortcuts_state(&self) -> ShortcutsState {
+        ShortcutsState {
+            use_shift_enter_hint: self.use_shift_enter_hint,
+            esc_backtrack_hint: self.esc_backtrack_hint,
+            keymap: self.keymap,
+            is_task_running: self.is_task_running,
+            esc_interrupts: self.esc_interrupts,
+            clipboard_images_supported: CLIPBOARD_IMAGES_SUPPORTED,
+            has_previous_message: self.has_previous_message,
+            highlight_keys: self.highlight_shortcut_keys,
+            // `footer_mode` carries the page and selection of an open
+            // overlay over.
+            page: 0,
+            selected: None,
+            table: self.shortcut_table,
         }
     }
 
     fn footer_mode(&self) -> FooterMode {
-        match self.footer_mode {
-            FooterMode::EscHint => FooterMode::EscHint,
-            FooterMode::ShortcutOverlay => FooterMode::ShortcutOverlay,
-            FooterMode::CtrlCReminder => FooterMode::CtrlCReminder,
-            FooterMode::ShortcutSummary if self.ctrl_c_quit_hint => FooterMode::CtrlCReminder,
-            FooterMode::ShortcutSummary if !self.is_empty() => FooterMode::ContextOnly,
-            other => other,
+        match &self.footer_mode {
+            // Refresh the payloads from live composer state so a stale
+            // snapshot never reaches the renderer.
+            FooterMode::EscHint { .. } => FooterMode::EscHint {
+                backtrack_armed: self.esc_backtrack_hint,
+            },
+            FooterMode::CompactShortcuts(_) => FooterMode::CompactShortcuts(self.shortcuts_state()),
+            FooterMode::ShortcutOverlay(state) => FooterMode::ShortcutOverlay(ShortcutsState {
+                page: state.page,
+                selected: state.selected,
+                ..self.shortcuts_state()
+            }),
+            FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutSummary | FooterMode::Paused
+                if self.ctrl_c_reminder_active(Instant::now()) =>
+            {
+                FooterMode::CtrlCReminder {
+                    expires_at: self
+                        .ctrl_c_reminder_armed_at
+                        .map(|armed_at| armed_at + CTRL_C_REMINDER_TIMEOUT),
+                }
+            }
+            FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutSummary
+                if self.awaiting_approval =>
+            {
+                FooterMode::AwaitingApproval
+            }
+            FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutSummary
+                if self.reconnecting.is_some() =>
+            {
+                self.reconnecting
+                    .map_or(FooterMode::ShortcutSummary, FooterMode::Reconnecting)
+            }
+            FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutSummary if !self.is_empty() => {
+                FooterMode::ContextOnly
+            }
+            FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutSummary
+                if !self.is_task_running =>
+            {
+                self.idle_or_summary()
+            }
+            FooterMode::CtrlCReminder { .. } => FooterMode::ShortcutSummary,
+            other => other.clone(),
+        }
+    }
+
+    fn idle_or_summary(&self) -> FooterMode {
+        transition_to_idle(
+            FooterMode::ShortcutSummary,
+            self.last_activity,
+            Instant::now(),
+            Duration::from_secs(IDLE_TIMEOUT_SECS),
+        )
+    }
+
+    /// When the shortcut summary will quiet down to `Idle`, so the caller can
+    /// schedule a redraw for it. `None` while it does not apply.
+    pub(crate) fn idle_deadline(&self) -> Option<Instant> {
+        let deadline = self.last_activity + Duration::from_secs(IDLE_TIMEOUT_SECS);
+        (self.footer_mode() == FooterMode::ShortcutSummary
+            && !self.is_task_running
+            && deadline > Instant::now())
+        .then_some(deadline)
+    }
+
+    pub(crate) fn set_footer_style(&mut self, style: FooterStyle) {
+        self.footer_style = style;
+    }
+
+    pub(crate) fn set_active_profile(&mut self, profile: Option<String>) {
+        self.active_profile = profile;
+    }
+
+    pub(crate) fn set_highlight_shortcut_keys(&mut self, highlight: bool) {
+        self.highlight_shortcut_keys = highlight;
+    }
+
+    pub(crate) fn set_keymap(&mut self, keymap: ComposerKeymap) {
+        self.keymap = keymap.into();
+    }
+
+    /// Keys the shortcut overlay advertises, for callers that rebind them.
+    pub(crate) fn set_shortcut_table(&mut self, table: ShortcutTable) {
+        self.shortcut_table = table;
+    }
+
+    /// Show or clear the paused indicator for a turn the user interrupted.
+    pub(crate) fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.footer_mode = FooterMode::Paused;
+        } else if self.footer_mode == FooterMode::Paused {
+            self.footer_mode = reset_mode_after_activity(FooterMode::Paused);
         }
     }
 
+    /// Show the approval keys in place of the shortcut summary while an
+    /// approval request is pending. The overlay can still be opened over it.
+    pub(crate) fn set_awaiting_approval(&mut self, awaiting: bool) {
+        self.awaiting_approval = awaiting;
+    }
+
+    pub(crate) fn is_awaiting_approval(&self) -> bool {
+        self.awaiting_approval
+    }
+
+    /// Draw only the footer into `area`, for the row the bottom pane keeps
+    /// under a view that covers the composer.
+    pub(crate) fn render_footer_row(&self, area: Rect, buf: &mut Buffer) {
+        self.footer_cache
+            .render(area, buf, &self.footer_props(), Instant::now());
+    }
+
+    /// Show the reconnect progress in place of the shortcut summary until the
+    /// stream resumes and this is cleared with `None`.
+    pub(crate) fn set_reconnecting(&mut self, state: Option<ReconnectState>) {
+        self.reconnecting = state;
+    }
+
+    pub(crate) fn is_reconnecting(&self) -> bool {
+        self.reconnecting.is_some()
+    }
+
+    /// True while the Ctrl+C reminder is armed and its window has not elapsed
+    /// as of `now`.
+    pub(crate) fn ctrl_c_reminder_active(&self, now: Instant) -> bool {
+        self.ctrl_c_quit_hint
+            && !self.ctrl_c_reminder_armed_at.is_some_and(|armed_at| {
+                ctrl_c_reminder_expired(armed_at, now, CTRL_C_REMINDER_TIMEOUT)
+            })
+    }
+
+    /// When the Ctrl+C countdown next changes, ending with the instant the
+    /// reminder expires and the footer reverts. `None` while it is not armed.
+    pub(crate) fn ctrl_c_reminder_next_tick(&self, now: Instant) -> Option<Instant> {
+        if !self.ctrl_c_reminder_active(now) {
+            return None;
+        }
+        self.ctrl_c_reminder_armed_at
+            .and_then(|armed_at| next_countdown_tick(armed_at + CTRL_C_REMINDER_TIMEOUT, now))
+    }
+
     fn custom_footer_height(&self) -> Option<u16> {
         self.footer_hint_override
             .as_ref()
@@ -1509,6 +1848,27 @@ impl ChatComposer {
 
     pub fn set_task_running(&mut self, running: bool) {
         self.is_task_running = running;
+        self.last_activity = Instant::now();
+        if !running {
+            self.task_started_at = None;
+        } else if self.task_started_at.is_none() {
+            self.task_started_at = Some(Instant::now());
+        }
+    }
+
+    /// Record whether esc reaches the status indicator (and interrupts the
+    /// running task) instead of the composer.
+    pub(crate) fn set_esc_interrupts(&mut self, esc_interrupts: bool) {
+        self.esc_interrupts = esc_interrupts;
+    }
+
+    /// Record whether the transcript holds a user message to backtrack to.
+    pub(crate) fn set_has_previous_message(&mut self, has_previous: bool) {
+        self.has_previous_message = has_previous;
+    }
+
+    pub(crate) fn set_sandbox_badge(&mut self, badge: Option<SandboxBadge>) {
+        self.sandbox_badge = badge;
     }
 
     pub(crate) fn set_context_window_percent(&mut self, percent: Option<u8>) {
@@ -1517,12 +1877,61 @@ impl ChatComposer {
         }
     }
 
+    pub(crate) fn set_context_window_tokens(&mut self, used: Option<u64>, max: Option<u64>) {
+        self.context_tokens_used = used;
+        self.context_tokens_max = max;
+    }
+
+    /// Show a transient notification in the footer that reverts to the
+    /// shortcut summary after `ttl`.
+    pub(crate) fn show_footer_notification(
+        &mut self,
+        message: Cow<'static, str>,
+        severity: NotificationSeverity,
+        ttl: Duration,
+    ) {
+        self.footer_mode = FooterMode::Notification {
+            message,
+            severity,
+            expires_at: Some(Instant::now() + ttl),
+        };
+    }
+
+    fn clear_expired_footer_notification(&mut self) {
+        if let FooterMode::Notification { expires_at, .. } = &self.footer_mode
+            && notification_expired(*expires_at, Instant::now())
+        {
+            self.footer_mode = FooterMode::ShortcutSummary;
+        }
+    }
+
+    pub(crate) fn set_queued_user_message_count(&mut self, count: usize) {
+        self.queued_user_messages = count;
+    }
+
+    pub(crate) fn set_tokens_per_second(&mut self, rate: Option<f32>) {
+        self.tokens_per_second = rate;
+    }
+
+    pub(crate) fn set_model_info(
+        &mut self,
+        model: Option<String>,
+        reasoning_effort: Option<ReasoningEffortConfig>,
+    ) {
+        self.model = model;
+        self.reasoning_effort = reasoning_effort;
+    }
+
     pub(crate) fn set_esc_backtrack_hint(&mut self, show: bool) {
         self.esc_backtrack_hint = show;
         if show {
-            self.footer_mode = esc_hint_mode(self.footer_mode, self.is_task_running);
+            self.footer_mode = esc_hint_mode(
+                self.footer_mode.clone(),
+                self.is_task_running,
+                self.esc_backtrack_hint,
+            );
         } else {
-            self.footer_mode = reset_mode_after_activity(self.footer_mode);
+            self.footer_mode = reset_mode_after_activity(self.footer_mode.clone());
         }
     }
 }
@@ -1540,8 +1949,8 @@ impl WidgetRef for ChatComposer {
             ActivePopup::None => {
                 let footer_props = self.footer_props();
                 let custom_height = self.custom_footer_height();
-                let footer_hint_height =
-                    custom_height.unwrap_or_else(|| footer_height(footer_props));
+                let footer_hint_height = custom_height
+                    .unwrap_or_else(|| self.footer_cache.height(&footer_props, popup_rect.width));
                 let footer_spacing = Self::footer_spacing(footer_hint_height);
                 let hint_rect = if footer_spacing > 0 && footer_hint_height > 0 {
                     let [_, hint_rect] = Layout::vertical([
@@ -1572,7 +1981,9 @@ impl WidgetRef for ChatComposer {
                         Line::from(spans).render_ref(custom_rect, buf);
                     }
                 } else {
-                    render_footer(hint_rect, buf, footer_props);
+                    self.footer_width.set(Some(hint_rect.width));
+                    self.footer_cache
+                        .render(hint_rect, buf, &footer_props, Instant::now());
                 }
             }
         }
@@ -1660,6 +2071,7 @@ mod tests {
     use crate::bottom_pane::InputResult;
     use crate::bottom_pane::chat_composer::AttachedImage;
     use crate::bottom_pane::chat_composer::LARGE_PASTE_CHAR_THRESHOLD;
+    use crate::bottom_pane::footer::footer_height;
     use crate::bottom_pane::prompt_args::extract_positional_args_for_prompt_line;
     use crate::bottom_pane::textarea::TextArea;
     use tokio::sync::mpsc::unbounded_channel;
@@ -1737,7 +2149,7 @@ mod tests {
         );
         setup(&mut composer);
         let footer_props = composer.footer_props();
-        let footer_lines = footer_height(footer_props);
+        let footer_lines = footer_height(&footer_props, width);
         let footer_spacing = ChatComposer::footer_spacing(footer_lines);
         let height = footer_lines + footer_spacing + 8;
         let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
@@ -1753,7 +2165,13 @@ mod tests {
         use crossterm::event::KeyEvent;
         use crossterm::event::KeyModifiers;
 
+        snapshot_composer_state("footer_mode_compact_shortcuts", true, |composer| {
+            let _ =
+                composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        });
+
         snapshot_composer_state("footer_mode_shortcut_overlay", true, |composer| {
+            composer.set_has_previous_message(true);
             composer.set_esc_backtrack_hint(true);
             let _ =
                 composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
@@ -1774,8 +2192,10 @@ mod tests {
         });
 
         snapshot_composer_state("footer_mode_esc_hint_from_overlay", true, |composer| {
-            let _ =
-                composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+            for _ in 0..2 {
+                let _ = composer
+                    .handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+            }
             let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
         });
 
@@ -1788,8 +2208,10 @@ mod tests {
             "footer_mode_overlay_then_external_esc_hint",
             true,
             |composer| {
-                let _ = composer
-                    .handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+                for _ in 0..2 {
+                    let _ = composer
+                        .handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+                }
                 composer.set_esc_backtrack_hint(true);
             },
         );
@@ -1828,6 +2250,197 @@ mod tests {
         assert!(!composer.esc_backtrack_hint);
     }
 
+    #[test]
+    fn ctrl_c_reminder_reverts_to_summary_after_timeout() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        composer.set_ctrl_c_quit_hint(true, true);
+        assert!(composer.ctrl_c_reminder_active(Instant::now()));
+        assert!(matches!(
+            composer.footer_props().mode,
+            FooterMode::CtrlCReminder { .. }
+        ));
+
+        // Simulate the double-press window elapsing.
+        composer.ctrl_c_reminder_armed_at = Some(Instant::now() - CTRL_C_REMINDER_TIMEOUT);
+        assert!(!composer.ctrl_c_reminder_active(Instant::now()));
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+    }
+
+    #[test]
+    fn ctrl_c_reminder_takes_priority_over_paused() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        composer.set_paused(true);
+        assert_eq!(composer.footer_props().mode, FooterMode::Paused);
+
+        composer.set_ctrl_c_quit_hint(true, true);
+        assert!(matches!(
+            composer.footer_props().mode,
+            FooterMode::CtrlCReminder { .. }
+        ));
+
+        // Once the reminder lapses the paused indicator comes back.
+        composer.ctrl_c_reminder_armed_at = Some(Instant::now() - CTRL_C_REMINDER_TIMEOUT);
+        assert_eq!(composer.footer_props().mode, FooterMode::Paused);
+
+        composer.set_paused(false);
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+    }
+
+    #[test]
+    fn awaiting_approval_replaces_summary_until_resolved() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        composer.set_awaiting_approval(true);
+        assert_eq!(composer.footer_props().mode, FooterMode::AwaitingApproval);
+
+        // `?` still cycles through the shortcuts, and closing them returns to
+        // the prompt.
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        assert!(matches!(
+            composer.footer_props().mode,
+            FooterMode::CompactShortcuts(_)
+        ));
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        assert!(matches!(
+            composer.footer_props().mode,
+            FooterMode::ShortcutOverlay(_)
+        ));
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        assert_eq!(composer.footer_props().mode, FooterMode::AwaitingApproval);
+
+        composer.set_awaiting_approval(false);
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+    }
+
+    #[test]
+    fn reconnecting_replaces_summary_until_cleared() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_task_running(true);
+        let state = ReconnectState {
+            attempt: 2,
+            max_attempts: 5,
+            next_retry_at: None,
+        };
+
+        composer.set_reconnecting(Some(state));
+        assert_eq!(
+            composer.footer_props().mode,
+            FooterMode::Reconnecting(state)
+        );
+
+        composer.set_reconnecting(None);
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+    }
+
+    #[test]
+    fn summary_goes_idle_without_input() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+        assert!(composer.idle_deadline().is_some());
+
+        composer.last_activity = Instant::now() - Duration::from_secs(IDLE_TIMEOUT_SECS);
+        assert_eq!(composer.footer_props().mode, FooterMode::Idle);
+        assert_eq!(composer.idle_deadline(), None);
+
+        // A running task keeps the full summary up.
+        composer.is_task_running = true;
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+        composer.is_task_running = false;
+
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(composer.footer_props().mode, FooterMode::ShortcutSummary);
+    }
+
+    #[test]
+    fn ctrl_k_runs_new_chat_shortcut_only_when_low_and_empty() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let ctrl_k = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+
+        composer.set_context_window_percent(Some(CONTEXT_CLEAR_PERCENT + 1));
+        let (result, _) = composer.handle_key_event(ctrl_k);
+        assert_eq!(result, InputResult::None);
+        assert!(rx.try_recv().is_err());
+
+        // The shortcut asks for confirmation before starting a new chat.
+        composer.set_context_window_percent(Some(CONTEXT_CLEAR_PERCENT));
+        let (result, _) = composer.handle_key_event(ctrl_k);
+        assert_eq!(result, InputResult::None);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::RunShortcut(ShortcutId::ClearContext))
+        ));
+
+        // With a draft, ctrl + k still kills to the end of the line.
+        composer.textarea.insert_str("draft");
+        composer.textarea.set_cursor(0);
+        let (result, _) = composer.handle_key_event(ctrl_k);
+        assert_eq!(result, InputResult::None);
+        assert!(composer.is_empty());
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn question_mark_only_toggles_on_first_char() {
         use crossterm::event::KeyCode;
@@ -1847,8 +2460,16 @@ mod tests {
         let (result, needs_redraw) =
             composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
         assert_eq!(result, InputResult::None);
-        assert!(needs_redraw, "toggling overlay should request redraw");
-        assert_eq!(composer.footer_mode, FooterMode::ShortcutOverlay);
+        assert!(needs_redraw, "toggling shortcuts should request redraw");
+        assert!(matches!(
+            composer.footer_mode,
+            FooterMode::CompactShortcuts(_)
+        ));
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        assert!(matches!(
+            composer.footer_mode,
+            FooterMode::ShortcutOverlay(_)
+        ));
 
         // Toggle back to prompt mode so subsequent typing captures characters.
         let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
@@ -1869,6 +2490,149 @@ mod tests {
         assert_eq!(composer.footer_mode(), FooterMode::ContextOnly);
     }
 
+    #[test]
+    fn question_mark_pages_through_narrow_overlay() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+        use ratatui::buffer::Buffer;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_has_previous_message(true);
+        let area = Rect::new(0, 0, 40, 20);
+        let mut modes = Vec::new();
+        for _ in 0..4 {
+            let _ =
+                composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+            composer.render_ref(area, &mut Buffer::empty(area));
+            modes.push(match composer.footer_props().mode {
+                FooterMode::CompactShortcuts(_) => "compact".to_string(),
+                FooterMode::ShortcutOverlay(state) => format!("page {}", state.page),
+                other => format!("{other:?}"),
+            });
+        }
+        assert_eq!(modes, ["compact", "page 0", "page 1", "ShortcutSummary"]);
+    }
+
+    #[test]
+    fn enter_runs_shortcut_selected_in_overlay() {
+        use crate::bottom_pane::footer::ShortcutId;
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+        use ratatui::buffer::Buffer;
+
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let area = Rect::new(0, 0, 40, 20);
+        let press = |composer: &mut ChatComposer, code| {
+            let _ = composer.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+            composer.render_ref(area, &mut Buffer::empty(area));
+        };
+        press(&mut composer, KeyCode::Char('?'));
+        press(&mut composer, KeyCode::Char('?'));
+
+        // Up from no selection lands on the last entry, turning the page.
+        press(&mut composer, KeyCode::Up);
+        let FooterMode::ShortcutOverlay(state) = composer.footer_props().mode else {
+            panic!("expected the shortcut overlay");
+        };
+        assert_eq!(state.selected, Some(ShortcutId::ShowTranscript));
+        assert_eq!(state.page, 1);
+        assert!(rx.try_recv().is_err());
+
+        press(&mut composer, KeyCode::Enter);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(AppEvent::RunShortcut(ShortcutId::ShowTranscript))
+        ));
+        assert_eq!(composer.footer_mode, FooterMode::ShortcutSummary);
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn vim_keymap_only_changes_the_overlay_hints() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_keymap(ComposerKeymap::Vim);
+        let press = |composer: &mut ChatComposer, code| {
+            let _ = composer.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        };
+
+        // Editing stays modeless: esc does not switch modes and letters insert.
+        type_chars_humanlike(&mut composer, &['h', 'i']);
+        press(&mut composer, KeyCode::Esc);
+        type_chars_humanlike(&mut composer, &['x']);
+        assert_eq!(composer.textarea.text(), "hix");
+
+        composer.textarea.set_text("");
+        for _ in 0..2 {
+            press(&mut composer, KeyCode::Char('?'));
+        }
+        match composer.footer_props().mode {
+            FooterMode::ShortcutOverlay(state) => assert_eq!(state.keymap, KeymapStyle::Vim),
+            other => panic!("expected the shortcut overlay, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn shortcut_overlay_uses_composer_shortcut_table() {
+        use crate::bottom_pane::footer::ShortcutId;
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        let table = ShortcutTable::default().remap(
+            ShortcutId::ShowTranscript,
+            crate::key_hint::ctrl(KeyCode::Char('o')),
+        );
+        composer.set_shortcut_table(table);
+
+        for _ in 0..2 {
+            let _ =
+                composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        }
+        match composer.footer_props().mode {
+            FooterMode::ShortcutOverlay(state) => assert_eq!(state.table, table),
+            other => panic!("expected the shortcut overlay, got {other:?}"),
+        }
+    }
+
     #[test]
     fn shortcut_overlay_persists_while_task_running() {
         use crossterm::event::KeyCode;
@@ -1885,13 +2649,25 @@ mod tests {
             false,
         );
 
-        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
-        assert_eq!(composer.footer_mode, FooterMode::ShortcutOverlay);
+        for _ in 0..2 {
+            let _ =
+                composer.handle_key_event(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        }
+        assert!(matches!(
+            composer.footer_mode,
+            FooterMode::ShortcutOverlay(_)
+        ));
 
         composer.set_task_running(true);
 
-        assert_eq!(composer.footer_mode, FooterMode::ShortcutOverlay);
-        assert_eq!(composer.footer_mode(), FooterMode::ShortcutOverlay);
+        assert!(matches!(
+            composer.footer_mode,
+            FooterMode::ShortcutOverlay(_)
+        ));
+        assert!(matches!(
+            composer.footer_mode(),
+            FooterMode::ShortcutOverlay(_)
+        ));
     }
 
     #[test]
@@ -1,333 +1,2045 @@
+use crate::exec_cell::spinner_at;
 use crate::key_hint;
-use crate::key_hint::KeyBinding;
+use crate::key_hint::KeyChord;
 use crate::render::line_utils::prefix_lines;
+use crate::render::line_utils::push_owned_lines;
 use crate::ui_consts::FOOTER_INDENT_COLS;
+use crate::wrapping::RtOptions;
+use crate::wrapping::word_wrap_line;
+use codex_core::config::Config;
+use codex_core::config_types::ComposerKeymap;
+use codex_core::config_types::FooterStyle;
+use codex_core::protocol_config_types::ReasoningEffort as ReasoningEffortConfig;
 use crossterm::event::KeyCode;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::style::Color;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::time::Duration;
+use std::time::Instant;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+use unicode_width::UnicodeWidthStr;
 
-#[derive(Clone, Copy, Debug)]
-pub(crate) struct FooterProps {
-    pub(crate) mode: FooterMode,
-    pub(crate) esc_backtrack_hint: bool,
-    pub(crate) use_shift_enter_hint: bool,
-    pub(crate) is_task_running: bool,
-    pub(crate) context_window_percent: Option<u8>,
+/// How long a footer notification stays visible before reverting to the
+/// shortcut summary.
+pub(crate) const FOOTER_NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How long the Ctrl+C reminder stays armed after the first press before the
+/// footer reverts to the shortcut summary.
+pub(crate) const CTRL_C_REMINDER_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Remaining context (in percent) below which the indicator turns bold.
+const CONTEXT_WARNING_PERCENT: u8 = 25;
+/// Remaining context (in percent) below which the indicator turns red and
+/// suggests `/compact`.
+const CONTEXT_CRITICAL_PERCENT: u8 = 10;
+/// Remaining context (in percent) at or below which the overlay offers ctrl + k
+/// to start a new chat, and the composer honors a second press of it.
+pub(crate) const CONTEXT_CLEAR_PERCENT: u8 = 15;
+
+/// Fewest blank columns between left-aligned content and a right-aligned
+/// segment on the same footer line.
+const RIGHT_ALIGN_MIN_GAP: usize = 2;
+
+/// Number of cells in the `Progress` bar.
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Most rows the shortcut overlay may take. Longer overlays are split into
+/// pages, the last row of each naming the page.
+const OVERLAY_MAX_ROWS: usize = 6;
+
+/// Everything the footer needs to render one frame.
+///
+/// Data only one mode needs travels in that mode's payload; the fields here
+/// describe the session and feed whichever line ends up showing the context
+/// indicator. They default to "off" via [`FooterProps::builder`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FooterProps {
+    pub mode: FooterMode,
+    /// Switches `CtrlCReminder` from "quit" to "interrupt" and enables the
+    /// elapsed time from `task_started_at`.
+    pub is_task_running: bool,
+    /// Sandbox reminder shown before the context indicator in
+    /// `ShortcutSummary` and `ContextOnly`.
+    pub sandbox_badge: Option<SandboxBadge>,
+    /// Remaining context shown by `ShortcutSummary` and `ContextOnly`.
+    /// `None` renders as 100%.
+    pub context_window_percent: Option<u8>,
+    /// Tokens currently occupying the context window. Shown alongside
+    /// `context_tokens_max` when both are known.
+    pub context_tokens_used: Option<u64>,
+    /// Size of the model's context window in tokens.
+    pub context_tokens_max: Option<u64>,
+    /// Throughput shown next to the context indicator while streaming.
+    pub tokens_per_second: Option<f32>,
+    /// Active model, shown right-aligned on the shortcut summary when there is
+    /// room for it.
+    pub model: Option<String>,
+    /// Reasoning effort appended to the model label, e.g. "gpt-5-codex · medium".
+    pub reasoning_effort: Option<ReasoningEffortConfig>,
+    /// Messages typed while a task runs, waiting to be sent. Shown with the
+    /// edit hint while `is_task_running`; zero hides it.
+    pub queued_user_messages: usize,
+    /// When the running task began; the context indicator is followed by the
+    /// time since then while `is_task_running`.
+    #[cfg_attr(feature = "serde", serde(with = "serde_instant"))]
+    pub task_started_at: Option<Instant>,
+    /// Config profile in use, listed under the shortcut overlay when set.
+    pub active_profile: Option<String>,
+    /// Blank columns before the footer content, for hosts whose layout does
+    /// not match the chat pane's. `None` uses `FOOTER_INDENT_COLS`.
+    pub indent_cols: Option<u16>,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub(crate) enum FooterMode {
-    CtrlCReminder,
+impl FooterProps {
+    pub fn builder() -> FooterPropsBuilder {
+        FooterPropsBuilder::default()
+    }
+
+    fn indent(&self) -> usize {
+        self.indent_cols.map_or(FOOTER_INDENT_COLS, usize::from)
+    }
+
+    /// Describes the first way these props contradict themselves, if any.
+    fn inconsistency(&self) -> Option<&'static str> {
+        if self
+            .context_window_percent
+            .is_some_and(|percent| percent > 100)
+        {
+            return Some("context_window_percent is above 100");
+        }
+        if self.context_tokens_used.is_some() != self.context_tokens_max.is_some() {
+            return Some("context_tokens_used and context_tokens_max must be set together");
+        }
+        if self.context_tokens_max == Some(0) {
+            return Some("context_tokens_max is zero");
+        }
+        if self
+            .tokens_per_second
+            .is_some_and(|rate| !rate.is_finite() || rate < 0.0)
+        {
+            return Some("tokens_per_second is negative or not finite");
+        }
+        None
+    }
+}
+
+/// Fluent constructor for [`FooterProps`]. Unset fields default to the
+/// shortcut summary with no hints, indicators or clock.
+#[derive(Clone, Debug)]
+pub struct FooterPropsBuilder {
+    props: FooterProps,
+}
+
+impl Default for FooterPropsBuilder {
+    fn default() -> Self {
+        Self {
+            props: FooterProps {
+                mode: FooterMode::ShortcutSummary,
+                is_task_running: false,
+                sandbox_badge: None,
+                context_window_percent: None,
+                context_tokens_used: None,
+                context_tokens_max: None,
+                tokens_per_second: None,
+                model: None,
+                reasoning_effort: None,
+                queued_user_messages: 0,
+                task_started_at: None,
+                active_profile: None,
+                indent_cols: None,
+            },
+        }
+    }
+}
+
+impl FooterPropsBuilder {
+    pub fn mode(mut self, mode: FooterMode) -> Self {
+        self.props.mode = mode;
+        self
+    }
+
+    pub fn task_running(mut self, running: bool) -> Self {
+        self.props.is_task_running = running;
+        self
+    }
+
+    pub fn sandbox_badge(mut self, badge: SandboxBadge) -> Self {
+        self.props.sandbox_badge = Some(badge);
+        self
+    }
+
+    pub fn context_window_percent(mut self, percent: u8) -> Self {
+        self.props.context_window_percent = Some(percent);
+        self
+    }
+
+    pub fn context_tokens(mut self, used: u64, max: u64) -> Self {
+        self.props.context_tokens_used = Some(used);
+        self.props.context_tokens_max = Some(max);
+        self
+    }
+
+    pub fn tokens_per_second(mut self, rate: f32) -> Self {
+        self.props.tokens_per_second = Some(rate);
+        self
+    }
+
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.props.model = Some(model.into());
+        self
+    }
+
+    pub fn reasoning_effort(mut self, effort: ReasoningEffortConfig) -> Self {
+        self.props.reasoning_effort = Some(effort);
+        self
+    }
+
+    pub fn queued_user_messages(mut self, count: usize) -> Self {
+        self.props.queued_user_messages = count;
+        self
+    }
+
+    pub fn task_started_at(mut self, started_at: Instant) -> Self {
+        self.props.task_started_at = Some(started_at);
+        self
+    }
+
+    pub fn active_profile(mut self, profile: impl Into<String>) -> Self {
+        self.props.active_profile = Some(profile.into());
+        self
+    }
+
+    pub fn indent_cols(mut self, cols: u16) -> Self {
+        self.props.indent_cols = Some(cols);
+        self
+    }
+
+    /// Debug builds panic if the props contradict each other: a context
+    /// window above 100%, only one of the used and max token counts set, a
+    /// zero token max, or a negative or non-finite tokens-per-second rate.
+    pub fn build(self) -> FooterProps {
+        debug_assert_eq!(self.props.inconsistency(), None, "inconsistent FooterProps");
+        self.props
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum FooterMode {
+    /// "ctrl + c again to quit/interrupt"; reads `is_task_running`. Counts
+    /// down to `expires_at`, the end of the double-press window, and falls
+    /// back to the shortcut summary once it passes.
+    CtrlCReminder {
+        #[cfg_attr(feature = "serde", serde(with = "serde_instant"))]
+        expires_at: Option<Instant>,
+    },
+    /// Transient message that reverts to the shortcut summary once
+    /// `expires_at` passes; `None` keeps it up until the mode changes.
+    Notification {
+        message: Cow<'static, str>,
+        severity: NotificationSeverity,
+        #[cfg_attr(feature = "serde", serde(with = "serde_instant"))]
+        expires_at: Option<Instant>,
+    },
+    /// Deterministic progress for a long-running operation, drawn as a bar with
+    /// the label and percentage. `percent` is clamped to 100.
+    Progress {
+        label: Cow<'static, str>,
+        percent: u8,
+    },
+    /// Context indicator followed by "? for shortcuts".
     ShortcutSummary,
-    ShortcutOverlay,
-    EscHint,
+    /// "/ commands · @ files · ? more" on one dim line: the first step of `?`,
+    /// between the summary and the full overlay.
+    CompactShortcuts(ShortcutsState),
+    /// Multi-column list of the shortcuts that apply to `ShortcutsState`.
+    ShortcutOverlay(ShortcutsState),
+    /// "esc esc to edit previous message", or "esc again ..." once
+    /// `backtrack_armed`.
+    EscHint { backtrack_armed: bool },
+    /// Only the context indicator, used while the user is typing.
     ContextOnly,
+    /// "paused · enter to resume" after the user interrupts a turn. Stays up
+    /// until the next turn starts or the user types; an armed Ctrl+C reminder
+    /// is shown over it.
+    Paused,
+    /// "⏸ waiting for approval" with the approval keys while a command or patch
+    /// waits on the user.
+    AwaitingApproval,
+    /// The shortcut summary after a stretch without input: only the context
+    /// indicator, without the hint, model or other extras.
+    Idle,
+    /// Spinner with "reconnecting… attempt 2/5 · retrying in 3s" while the
+    /// turn is retried after the model stream dropped.
+    Reconnecting(ReconnectState),
+    /// Nothing to show, per the `tui.footer` setting. `keep_row` leaves a blank
+    /// row so the composer does not move; otherwise the footer takes no rows.
+    Empty { keep_row: bool },
+}
+
+/// What the shortcut overlay needs, beyond the [`FooterProps`] it is drawn
+/// with, to pick which bindings to list.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShortcutsState {
+    /// Advertise shift + enter instead of ctrl + j for newlines.
+    pub use_shift_enter_hint: bool,
+    /// Primes the "esc again" wording of the edit-previous entry.
+    pub esc_backtrack_hint: bool,
+    /// Composer key bindings to describe.
+    pub keymap: KeymapStyle,
+    /// A turn is in progress, so it can be interrupted.
+    pub is_task_running: bool,
+    /// The status indicator is up, so esc interrupts the running turn;
+    /// otherwise only ctrl + c does.
+    pub esc_interrupts: bool,
+    /// Images can be read from the system clipboard on this platform.
+    pub clipboard_images_supported: bool,
+    /// The transcript has a user message that backtracking can return to.
+    pub has_previous_message: bool,
+    /// Draw the overlay's keys at full brightness against their dimmed
+    /// labels; off keeps the whole overlay uniformly dim.
+    pub highlight_keys: bool,
+    /// Zero-based overlay page on display when the overlay is paginated.
+    pub page: usize,
+    /// Entry picked with the arrow keys; enter runs its action.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) selected: Option<ShortcutId>,
+    /// Keys rebound from the defaults in `SHORTCUTS`, so the overlay lists
+    /// what will actually work.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) table: ShortcutTable,
+}
+
+/// The shortcut table in effect: the static `SHORTCUTS` descriptors with any
+/// keys the user rebound. Display conditions still come from the descriptors,
+/// so a rebound entry shows exactly when its default would.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct ShortcutTable {
+    remapped: [Option<KeyChord>; SHORTCUT_ID_COUNT],
+}
+
+impl ShortcutTable {
+    /// The table for a session configured with `config`.
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self::default().remap(
+            ShortcutId::ShowTranscript,
+            key_hint::transcript_key(config.tui_transcript_key.as_deref()),
+        )
+    }
+
+    pub(crate) fn remap(mut self, id: ShortcutId, key: impl Into<KeyChord>) -> Self {
+        self.remapped[id as usize] = Some(key.into());
+        self
+    }
+
+    fn remapped(&self, id: ShortcutId) -> Option<KeyChord> {
+        self.remapped[id as usize]
+    }
+}
+
+/// Where the retries after a dropped model stream stand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReconnectState {
+    /// 1-based number of the upcoming attempt.
+    pub attempt: u64,
+    pub max_attempts: u64,
+    /// When the next attempt starts; the footer counts down to it.
+    #[cfg_attr(feature = "serde", serde(with = "serde_instant"))]
+    pub next_retry_at: Option<Instant>,
+}
+
+/// Short reminder of how much the agent may do without asking, named after
+/// the `/approvals` presets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SandboxBadge {
+    ReadOnly,
+    Auto,
+    FullAccess,
+}
+
+impl SandboxBadge {
+    fn label(self) -> &'static str {
+        match self {
+            SandboxBadge::ReadOnly => "[read-only]",
+            SandboxBadge::Auto => "[auto]",
+            SandboxBadge::FullAccess => "[full-access]",
+        }
+    }
+}
+
+/// Family of composer key bindings; selects which bindings the shortcut
+/// overlay lists.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum KeymapStyle {
+    /// Readline-style chords such as `ctrl + j`.
+    #[default]
+    Emacs,
+    /// Adds the vim-style mode keys to the overlay.
+    Vim,
+}
+
+impl From<ComposerKeymap> for KeymapStyle {
+    fn from(keymap: ComposerKeymap) -> Self {
+        match keymap {
+            ComposerKeymap::Emacs => KeymapStyle::Emacs,
+            ComposerKeymap::Vim => KeymapStyle::Vim,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NotificationSeverity {
+    Info,
+    Warn,
+    Error,
 }
 
-pub(crate) fn toggle_shortcut_mode(current: FooterMode, ctrl_c_hint: bool) -> FooterMode {
-    if ctrl_c_hint && matches!(current, FooterMode::CtrlCReminder) {
-        return current;
+/// `Instant` has no fixed origin, so the footer's deadlines and start times are
+/// serialized as milliseconds since the Unix epoch, anchored to the wall clock
+/// at the moment they are written, and read back against it to the millisecond.
+#[cfg(feature = "serde")]
+mod serde_instant {
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+    use std::time::Duration;
+    use std::time::Instant;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    pub(super) fn serialize<S: Serializer>(
+        instant: &Option<Instant>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        instant.map(unix_millis).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Instant>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(from_unix_millis))
+    }
+
+    fn from_unix_millis(millis: u64) -> Instant {
+        let now = Instant::now();
+        let at = UNIX_EPOCH + Duration::from_millis(millis);
+        match at.duration_since(SystemTime::now()) {
+            Ok(ahead) => now.checked_add(ahead),
+            Err(behind) => now.checked_sub(behind.duration()),
+        }
+        .unwrap_or(now)
+    }
+
+    pub(super) fn unix_millis(instant: Instant) -> u64 {
+        let now = Instant::now();
+        let wall = SystemTime::now();
+        let at = if instant <= now {
+            wall.checked_sub(now - instant)
+        } else {
+            wall.checked_add(instant - now)
+        };
+        at.and_then(|at| at.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |since| {
+                u64::try_from(since.as_millis()).unwrap_or(u64::MAX)
+            })
     }
+}
 
+/// Next footer mode after the user presses `?`. From the shortcut summary it
+/// cycles through the compact line and the full overlay, both listing the
+/// shortcuts for `shortcuts`; every other mode keeps its payload. An overlay
+/// spanning `overlay_pages` pages steps through them before closing.
+///
+/// | current            | ctrl_c_hint | next                              |
+/// |--------------------|-------------|-----------------------------------|
+/// | `CtrlCReminder`    | true        | `CtrlCReminder`                   |
+/// | `CtrlCReminder`    | false       | `ShortcutSummary`                 |
+/// | `ShortcutOverlay`  | any         | next page, else `ShortcutSummary` |
+/// | `Notification`     | any         | `Notification`                    |
+/// | `Paused`           | any         | `Paused`                          |
+/// | `ShortcutSummary`  | any         | `CompactShortcuts`                |
+/// | `Idle`             | any         | `CompactShortcuts`                |
+/// | `CompactShortcuts` | any         | `ShortcutOverlay`                 |
+/// | `AwaitingApproval` | any         | `ShortcutOverlay`                 |
+/// | `Reconnecting`     | any         | `ShortcutOverlay`                 |
+/// | `Progress`         | any         | `ShortcutOverlay`                 |
+/// | `EscHint`          | any         | `ShortcutOverlay`                 |
+/// | `ContextOnly`      | any         | `ShortcutOverlay`                 |
+/// | `Empty`            | any         | `ShortcutOverlay`                 |
+///
+/// The match is deliberately exhaustive so new modes must pick a transition.
+pub(crate) fn toggle_shortcut_mode(
+    current: FooterMode,
+    ctrl_c_hint: bool,
+    shortcuts: ShortcutsState,
+    overlay_pages: usize,
+) -> FooterMode {
     match current {
-        FooterMode::ShortcutOverlay | FooterMode::CtrlCReminder => FooterMode::ShortcutSummary,
-        _ => FooterMode::ShortcutOverlay,
+        FooterMode::CtrlCReminder { .. } if ctrl_c_hint => current,
+        FooterMode::ShortcutOverlay(state) if state.page + 1 < overlay_pages => {
+            FooterMode::ShortcutOverlay(ShortcutsState {
+                page: state.page + 1,
+                ..shortcuts
+            })
+        }
+        FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutOverlay(_) => {
+            FooterMode::ShortcutSummary
+        }
+        // Callers clear expired notifications first, so one still present here
+        // is live and should not be replaced by the overlay.
+        FooterMode::Notification { .. } => current,
+        // The paused indicator is the only sign the task can be resumed, so it
+        // is not traded for the overlay.
+        FooterMode::Paused => current,
+        FooterMode::ShortcutSummary | FooterMode::Idle => FooterMode::CompactShortcuts(shortcuts),
+        FooterMode::CompactShortcuts(_)
+        | FooterMode::AwaitingApproval
+        | FooterMode::Reconnecting(_)
+        | FooterMode::Progress { .. }
+        | FooterMode::EscHint { .. }
+        | FooterMode::ContextOnly
+        | FooterMode::Empty { .. } => FooterMode::ShortcutOverlay(shortcuts),
     }
 }
 
-pub(crate) fn esc_hint_mode(current: FooterMode, is_task_running: bool) -> FooterMode {
+pub(crate) fn esc_hint_mode(
+    current: FooterMode,
+    is_task_running: bool,
+    backtrack_armed: bool,
+) -> FooterMode {
     if is_task_running {
         current
     } else {
-        FooterMode::EscHint
+        FooterMode::EscHint { backtrack_armed }
     }
 }
 
 pub(crate) fn reset_mode_after_activity(current: FooterMode) -> FooterMode {
     match current {
-        FooterMode::EscHint
-        | FooterMode::ShortcutOverlay
-        | FooterMode::CtrlCReminder
-        | FooterMode::ContextOnly => FooterMode::ShortcutSummary,
+        FooterMode::EscHint { .. }
+        | FooterMode::CompactShortcuts(_)
+        | FooterMode::ShortcutOverlay(_)
+        | FooterMode::CtrlCReminder { .. }
+        | FooterMode::Notification { .. }
+        | FooterMode::Progress { .. }
+        | FooterMode::ContextOnly
+        | FooterMode::Paused
+        | FooterMode::AwaitingApproval
+        | FooterMode::Idle
+        | FooterMode::Reconnecting(_) => FooterMode::ShortcutSummary,
         other => other,
     }
 }
 
-pub(crate) fn footer_height(props: FooterProps) -> u16 {
-    footer_lines(props).len() as u16
-}
-
-pub(crate) fn render_footer(area: Rect, buf: &mut Buffer, props: FooterProps) {
-    Paragraph::new(prefix_lines(
-        footer_lines(props),
-        " ".repeat(FOOTER_INDENT_COLS).into(),
-        " ".repeat(FOOTER_INDENT_COLS).into(),
-    ))
-    .render(area, buf);
+/// Quiets the shortcut summary to `Idle` once `threshold` has passed between
+/// `last_activity` and `now`; any other mode is returned unchanged. Cheap
+/// enough to call on every frame.
+pub(crate) fn transition_to_idle(
+    current: FooterMode,
+    last_activity: Instant,
+    now: Instant,
+    threshold: Duration,
+) -> FooterMode {
+    match current {
+        FooterMode::ShortcutSummary
+            if now.saturating_duration_since(last_activity) >= threshold =>
+        {
+            FooterMode::Idle
+        }
+        other => other,
+    }
 }
 
-fn footer_lines(props: FooterProps) -> Vec<Line<'static>> {
-    // Show the context indicator on the left, appended after the primary hint
-    // (e.g., "? for shortcuts"). Keep it visible even when typing (i.e., when
-    // the shortcut hint is hidden). Hide it only for the multi-line
-    // ShortcutOverlay.
-    match props.mode {
-        FooterMode::CtrlCReminder => vec![ctrl_c_reminder_line(CtrlCReminderState {
-            is_task_running: props.is_task_running,
-        })],
-        FooterMode::ShortcutSummary => {
-            let mut line = context_window_line(props.context_window_percent);
-            line.push_span(" · ".dim());
-            line.extend(vec![
-                key_hint::plain(KeyCode::Char('?')).into(),
-                " for shortcuts".dim(),
-            ]);
-            vec![line]
+/// Applies the `tui.footer` setting to the mode the composer picked. `Minimal`
+/// blanks the shortcut summary; `Hidden` also drops the context-only line so
+/// an idle footer takes no rows. Everything else is shown as usual.
+pub(crate) fn apply_footer_style(mode: FooterMode, style: FooterStyle) -> FooterMode {
+    match (style, mode) {
+        (FooterStyle::Minimal, FooterMode::ShortcutSummary | FooterMode::Idle) => {
+            FooterMode::Empty { keep_row: true }
         }
-        FooterMode::ShortcutOverlay => shortcut_overlay_lines(ShortcutsState {
-            use_shift_enter_hint: props.use_shift_enter_hint,
-            esc_backtrack_hint: props.esc_backtrack_hint,
-        }),
-        FooterMode::EscHint => vec![esc_hint_line(props.esc_backtrack_hint)],
-        FooterMode::ContextOnly => vec![context_window_line(props.context_window_percent)],
+        (
+            FooterStyle::Hidden,
+            FooterMode::ShortcutSummary | FooterMode::ContextOnly | FooterMode::Idle,
+        ) => FooterMode::Empty { keep_row: false },
+        (_, mode) => mode,
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-struct CtrlCReminderState {
-    is_task_running: bool,
+/// Returns true once a notification armed with `expires_at` should no longer be
+/// shown.
+pub(crate) fn notification_expired(expires_at: Option<Instant>, now: Instant) -> bool {
+    expires_at.is_some_and(|expires_at| now >= expires_at)
 }
 
-#[derive(Clone, Copy, Debug)]
-struct ShortcutsState {
-    use_shift_enter_hint: bool,
-    esc_backtrack_hint: bool,
+/// Returns true once a Ctrl+C reminder armed at `armed_at` has been up for at
+/// least `timeout`.
+pub(crate) fn ctrl_c_reminder_expired(armed_at: Instant, now: Instant, timeout: Duration) -> bool {
+    now.saturating_duration_since(armed_at) >= timeout
 }
 
-fn ctrl_c_reminder_line(state: CtrlCReminderState) -> Line<'static> {
-    let action = if state.is_task_running {
-        "interrupt"
-    } else {
-        "quit"
-    };
-    Line::from(vec![
-        key_hint::ctrl(KeyCode::Char('c')).into(),
-        format!(" again to {action}").into(),
-    ])
-    .dim()
+/// Rows the footer takes at `width`. Deadlines only swap one line for
+/// another, so the count does not depend on the clock.
+///
+/// Every mode takes at least one row except `FooterMode::Empty { keep_row:
+/// false }`, which is how `tui.footer = "hidden"` collapses the footer; callers
+/// lay out zero rows for it rather than clamping.
+pub(crate) fn footer_height(props: &FooterProps, width: u16) -> u16 {
+    let rows = footer_lines(props, width, Instant::now()).len() as u16;
+    debug_assert!(
+        rows > 0 || props.mode == FooterMode::Empty { keep_row: false },
+        "only a collapsed footer may take no rows"
+    );
+    rows
 }
 
-fn esc_hint_line(esc_backtrack_hint: bool) -> Line<'static> {
-    let esc = key_hint::plain(KeyCode::Esc);
-    if esc_backtrack_hint {
-        Line::from(vec![esc.into(), " again to edit previous message".into()]).dim()
-    } else {
-        Line::from(vec![
-            esc.into(),
-            " ".into(),
-            esc.into(),
-            " to edit previous message".into(),
-        ])
-        .dim()
-    }
+/// Draws the footer as of `now`, the frame's timestamp: countdowns, elapsed
+/// times and the spinner all read this one instant so they agree.
+pub(crate) fn render_footer(area: Rect, buf: &mut Buffer, props: &FooterProps, now: Instant) {
+    render_footer_lines(area, buf, &footer_layout(props, area.width, now));
 }
 
-fn shortcut_overlay_lines(state: ShortcutsState) -> Vec<Line<'static>> {
-    let mut commands = Line::from("");
-    let mut newline = Line::from("");
-    let mut file_paths = Line::from("");
-    let mut paste_image = Line::from("");
-    let mut edit_previous = Line::from("");
-    let mut quit = Line::from("");
-    let mut show_transcript = Line::from("");
-
-    for descriptor in SHORTCUTS {
-        if let Some(text) = descriptor.overlay_entry(state) {
-            match descriptor.id {
-                ShortcutId::Commands => commands = text,
-                ShortcutId::InsertNewline => newline = text,
-                ShortcutId::FilePaths => file_paths = text,
-                ShortcutId::PasteImage => paste_image = text,
-                ShortcutId::EditPrevious => edit_previous = text,
-                ShortcutId::Quit => quit = text,
-                ShortcutId::ShowTranscript => show_transcript = text,
-            }
-        }
-    }
+/// Draws the footer into a buffer of its own height at `width` and returns
+/// each row as plain text with trailing blanks trimmed, for tests that check
+/// what the footer says rather than how it is styled.
+#[cfg(test)]
+pub(crate) fn render_footer_to_string(width: u16, props: FooterProps) -> Vec<String> {
+    let area = Rect::new(0, 0, width, footer_height(&props, width));
+    let mut buf = Buffer::empty(area);
+    render_footer(area, &mut buf, &props, Instant::now());
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
 
-    let ordered = vec![
-        commands,
-        newline,
-        file_paths,
-        paste_image,
-        edit_previous,
-        quit,
-        Line::from(""),
-        show_transcript,
-    ];
+/// Memoizes the footer layout for the last props and width it was asked for.
+///
+/// The composer redraws the footer every frame, and most frames show exactly
+/// what the previous one did. Reusing the laid-out lines skips rebuilding the
+/// spans (and, for the overlay, the column grid). Lines that follow the clock
+/// keep their layout too: each frame rebuilds only their [`ClockSpan`]s, for
+/// as long as the [`ClockShape`] holds.
+#[derive(Debug, Default)]
+pub(crate) struct FooterLineCache {
+    entry: RefCell<Option<CachedFooter>>,
+}
 
-    build_columns(ordered)
+#[derive(Debug)]
+struct CachedFooter {
+    props: FooterProps,
+    width: u16,
+    shape: ClockShape,
+    lines: Vec<Line<'static>>,
+    /// Line and span index of each clock span drawn in `lines`. `None` when
+    /// they could not be pinned down, so the layout is never reused.
+    clock_spans: Option<Vec<(ClockSpan, usize, usize)>>,
 }
 
-fn build_columns(entries: Vec<Line<'static>>) -> Vec<Line<'static>> {
-    if entries.is_empty() {
-        return Vec::new();
+impl FooterLineCache {
+    pub(crate) fn height(&self, props: &FooterProps, width: u16) -> u16 {
+        self.with_lines(props, width, Instant::now(), |lines| lines.len() as u16)
     }
 
-    const COLUMNS: usize = 2;
-    const COLUMN_PADDING: [usize; COLUMNS] = [4, 4];
-    const COLUMN_GAP: usize = 4;
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer, props: &FooterProps, now: Instant) {
+        self.with_lines(props, area.width, now, |lines| {
+            render_footer_lines(area, buf, lines);
+        });
+    }
 
-    let rows = entries.len().div_ceil(COLUMNS);
-    let target_len = rows * COLUMNS;
-    let mut entries = entries;
-    if entries.len() < target_len {
-        entries.extend(std::iter::repeat_n(
-            Line::from(""),
-            target_len - entries.len(),
-        ));
+    fn with_lines<T>(
+        &self,
+        props: &FooterProps,
+        width: u16,
+        now: Instant,
+        f: impl FnOnce(&[Line<'static>]) -> T,
+    ) -> T {
+        let shape = ClockShape::at(props, now);
+        let mut entry = self.entry.borrow_mut();
+        let hit = entry.as_ref().is_some_and(|cached| {
+            cached.clock_spans.is_some()
+                && cached.width == width
+                && cached.shape == shape
+                && cached.props == *props
+        });
+        match entry.as_mut() {
+            Some(cached) if hit => cached.refresh_clock_spans(now),
+            _ => *entry = Some(CachedFooter::lay_out(props, width, now, shape)),
+        }
+        f(entry.as_ref().map_or(&[], |cached| cached.lines.as_slice()))
     }
+}
 
-    let mut column_widths = [0usize; COLUMNS];
+impl CachedFooter {
+    fn lay_out(props: &FooterProps, width: u16, now: Instant, shape: ClockShape) -> Self {
+        let (lines, clipped) = clipped_footer_layout(props, width, now);
+        // An ellipsis may have cut a clock span short, and a cut span cannot
+        // be found again to refresh.
+        let clock_spans = if clipped && shape.has_clock_spans() {
+            None
+        } else {
+            locate_clock_spans(&lines, props, now)
+        };
+        Self {
+            props: props.clone(),
+            width,
+            shape,
+            lines,
+            clock_spans,
+        }
+    }
 
-    for (idx, entry) in entries.iter().enumerate() {
-        let column = idx % COLUMNS;
-        column_widths[column] = column_widths[column].max(entry.width());
+    fn refresh_clock_spans(&mut self, now: Instant) {
+        for &(clock, line, span) in self.clock_spans.iter().flatten() {
+            if let Some(fresh) = clock.span_at(&self.props, now) {
+                self.lines[line].spans[span] = fresh;
+            }
+        }
     }
+}
 
-    for (idx, width) in column_widths.iter_mut().enumerate() {
-        *width += COLUMN_PADDING[idx];
+/// Finds where each clock span `props` call for at `now` sits in `lines`. One
+/// the current line does not draw is left out; one whose text also shows up
+/// elsewhere cannot be told apart, so the whole layout is given up on.
+fn locate_clock_spans(
+    lines: &[Line<'static>],
+    props: &FooterProps,
+    now: Instant,
+) -> Option<Vec<(ClockSpan, usize, usize)>> {
+    let mut found = Vec::new();
+    for clock in ClockSpan::ALL {
+        let Some(expected) = clock.span_at(props, now) else {
+            continue;
+        };
+        let mut matches = lines.iter().enumerate().flat_map(|(line_idx, line)| {
+            line.spans
+                .iter()
+                .enumerate()
+                .filter(|(_, span)| **span == expected)
+                .map(move |(span_idx, _)| (line_idx, span_idx))
+        });
+        match (matches.next(), matches.next()) {
+            (Some((line, span)), None) => found.push((clock, line, span)),
+            (Some(_), Some(_)) => return None,
+            (None, _) => {}
+        }
     }
+    Some(found)
+}
 
-    entries
-        .chunks(COLUMNS)
-        .map(|chunk| {
-            let mut line = Line::from("");
-            for (col, entry) in chunk.iter().enumerate() {
-                line.extend(entry.spans.clone());
-                if col < COLUMNS - 1 {
-                    let target_width = column_widths[col];
-                    let padding = target_width.saturating_sub(entry.width()) + COLUMN_GAP;
-                    line.push_span(Span::from(" ".repeat(padding)));
-                }
+/// A span whose text follows the clock while the rest of its line holds
+/// still.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ClockSpan {
+    /// Time since the running task started, after the context indicator.
+    TaskElapsed,
+    /// Seconds left on the Ctrl+C reminder.
+    ReminderCountdown,
+    /// Spinner leading the reconnect line.
+    ReconnectSpinner,
+    /// Seconds until the next reconnect attempt.
+    RetryCountdown,
+}
+
+impl ClockSpan {
+    const ALL: [ClockSpan; 4] = [
+        ClockSpan::TaskElapsed,
+        ClockSpan::ReminderCountdown,
+        ClockSpan::ReconnectSpinner,
+        ClockSpan::RetryCountdown,
+    ];
+
+    /// The span as of `now`, or `None` when `props` do not call for it then.
+    fn span_at(self, props: &FooterProps, now: Instant) -> Option<Span<'static>> {
+        match (self, &props.mode) {
+            (ClockSpan::TaskElapsed, _) => task_elapsed_span(props, now),
+            (
+                ClockSpan::ReminderCountdown,
+                FooterMode::CtrlCReminder {
+                    expires_at: Some(deadline),
+                },
+            ) if now < *deadline => Some(reminder_countdown_span(
+                deadline.saturating_duration_since(now),
+            )),
+            (ClockSpan::ReconnectSpinner, FooterMode::Reconnecting(_)) => Some(spinner_at(now)),
+            (ClockSpan::RetryCountdown, FooterMode::Reconnecting(state)) => {
+                retry_countdown_span(*state, now)
             }
-            line.dim()
-        })
-        .collect()
+            _ => None,
+        }
+    }
 }
 
-fn context_window_line(percent: Option<u8>) -> Line<'static> {
-    let percent = percent.unwrap_or(100);
-    Line::from(vec![Span::from(format!("{percent}% context left")).dim()])
+/// The parts of the footer's layout that move with the clock: whether a
+/// deadline has swapped the line for the shortcut summary, and how wide each
+/// clock span is. Layouts taken at two instants with the same shape differ
+/// only in the text of their clock spans.
+#[derive(Debug, PartialEq)]
+struct ClockShape {
+    expired: bool,
+    widths: [Option<usize>; ClockSpan::ALL.len()],
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum ShortcutId {
-    Commands,
-    InsertNewline,
-    FilePaths,
-    PasteImage,
-    EditPrevious,
-    Quit,
-    ShowTranscript,
+impl ClockShape {
+    fn at(props: &FooterProps, now: Instant) -> Self {
+        let expired = match &props.mode {
+            FooterMode::CtrlCReminder { expires_at }
+            | FooterMode::Notification { expires_at, .. } => notification_expired(*expires_at, now),
+            _ => false,
+        };
+        Self {
+            expired,
+            widths: ClockSpan::ALL.map(|clock| clock.span_at(props, now).map(|span| span.width())),
+        }
+    }
+
+    fn has_clock_spans(&self) -> bool {
+        self.widths.iter().any(Option::is_some)
+    }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-struct ShortcutBinding {
-    key: KeyBinding,
-    condition: DisplayCondition,
+/// Renders already laid-out footer lines without copying their text.
+fn render_footer_lines(area: Rect, buf: &mut Buffer, lines: &[Line<'static>]) {
+    let borrowed: Vec<Line<'_>> = lines
+        .iter()
+        .map(|line| Line {
+            spans: line
+                .spans
+                .iter()
+                .map(|span| Span::styled(span.content.as_ref(), span.style))
+                .collect(),
+            ..*line
+        })
+        .collect();
+    Paragraph::new(borrowed).render(area, buf);
 }
 
-impl ShortcutBinding {
-    fn matches(&self, state: ShortcutsState) -> bool {
-        self.condition.matches(state)
-    }
+/// Lays out the footer for `width` columns: drops the token rate if it does
+/// not fit, ellipsizes what still overflows and applies the indent.
+fn footer_layout(props: &FooterProps, width: u16, now: Instant) -> Vec<Line<'static>> {
+    clipped_footer_layout(props, width, now).0
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum DisplayCondition {
-    Always,
-    WhenShiftEnterHint,
-    WhenNotShiftEnterHint,
+/// [`footer_layout`], also telling whether any line had to be ellipsized.
+fn clipped_footer_layout(
+    props: &FooterProps,
+    width: u16,
+    now: Instant,
+) -> (Vec<Line<'static>>, bool) {
+    let mut lines = footer_lines(props, width, now);
+    // The token rate is the least important segment, so drop it first when the
+    // footer would not fit on a narrow terminal.
+    if props.tokens_per_second.is_some() && !lines_fit(&lines, width, props.indent()) {
+        lines = footer_lines(
+            &FooterProps {
+                tokens_per_second: None,
+                ..props.clone()
+            },
+            width,
+            now,
+        );
+    }
+    let clipped = !lines_fit(&lines, width, props.indent());
+    let available = usize::from(width).saturating_sub(props.indent());
+    let lines = lines
+        .into_iter()
+        .map(|line| ellipsize_line(line, available))
+        .collect();
+    let indent = " ".repeat(props.indent());
+    (
+        prefix_lines(lines, indent.clone().into(), indent.into()),
+        clipped,
+    )
 }
 
-impl DisplayCondition {
-    fn matches(self, state: ShortcutsState) -> bool {
-        match self {
-            DisplayCondition::Always => true,
-            DisplayCondition::WhenShiftEnterHint => state.use_shift_enter_hint,
-            DisplayCondition::WhenNotShiftEnterHint => !state.use_shift_enter_hint,
-        }
+/// Formats a running task's duration: `42.3s` under a minute, `1m 42s` under
+/// an hour, and `1h 03m` beyond that.
+fn format_task_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        return format!("{:.1}s", elapsed.as_secs_f32());
+    }
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else {
+        format!("{minutes}m {seconds:02}s")
     }
 }
 
-struct ShortcutDescriptor {
-    id: ShortcutId,
-    bindings: &'static [ShortcutBinding],
-    prefix: &'static str,
-    label: &'static str,
+fn lines_fit(lines: &[Line<'static>], width: u16, indent: usize) -> bool {
+    let available = usize::from(width).saturating_sub(indent);
+    lines.iter().all(|line| line.width() <= available)
 }
 
-impl ShortcutDescriptor {
-    fn binding_for(&self, state: ShortcutsState) -> Option<&'static ShortcutBinding> {
-        self.bindings.iter().find(|binding| binding.matches(state))
+/// Shortens `line` to at most `max_width` columns, marking the cut with `…`.
+///
+/// Footer labels always start with a space after the key hint they describe,
+/// so those spans are shortened (right to left) before anything else. This
+/// keeps the key hints visible for as long as possible.
+fn ellipsize_line(line: Line<'static>, max_width: usize) -> Line<'static> {
+    const ELLIPSIS: &str = "…";
+
+    let width = line.width();
+    if width <= max_width {
+        return line;
+    }
+    if max_width == 0 {
+        return Line::default().style(line.style);
     }
 
-    fn overlay_entry(&self, state: ShortcutsState) -> Option<Line<'static>> {
-        let binding = self.binding_for(state)?;
-        let mut line = Line::from(vec![self.prefix.into(), binding.key.into()]);
-        match self.id {
-            ShortcutId::EditPrevious => {
-                if state.esc_backtrack_hint {
-                    line.push_span(" again to edit previous message");
-                } else {
-                    line.extend(vec![
-                        " ".into(),
-                        key_hint::plain(KeyCode::Esc).into(),
-                        " to edit previous message".into(),
-                    ]);
-                }
+    let style = line.style;
+    let mut spans = line.spans;
+    let mut excess = width - max_width + ELLIPSIS.width();
+    let mut cut_at = None;
+    for idx in (0..spans.len()).rev() {
+        if excess == 0 {
+            break;
+        }
+        if !spans[idx].content.starts_with(' ') {
+            continue;
+        }
+        let span_width = spans[idx].width();
+        let keep = span_width.saturating_sub(excess);
+        excess -= span_width - keep;
+        if keep <= 1 {
+            spans.remove(idx);
+            cut_at = Some(idx);
+        } else {
+            let content = truncate_to_width(&spans[idx].content, keep);
+            spans[idx].content = content.into();
+            cut_at = Some(idx + 1);
+        }
+    }
+
+    if excess > 0 {
+        // Labels alone were not enough; fall back to cutting from the right.
+        let mut remaining = max_width - ELLIPSIS.width();
+        let mut kept = Vec::with_capacity(spans.len());
+        for mut span in spans {
+            let span_width = span.width();
+            if span_width > remaining {
+                span.content = truncate_to_width(&span.content, remaining).into();
+                remaining = 0;
+            } else {
+                remaining -= span_width;
             }
-            _ => line.push_span(self.label),
-        };
-        Some(line)
+            if !span.content.is_empty() {
+                kept.push(span);
+            }
+            if remaining == 0 {
+                break;
+            }
+        }
+        cut_at = Some(kept.len());
+        spans = kept;
     }
+
+    let idx = cut_at.unwrap_or(spans.len()).min(spans.len());
+    let ellipsis_style = spans
+        .get(idx.saturating_sub(1))
+        .map(|span| span.style)
+        .unwrap_or_default();
+    spans.insert(idx, Span::styled(ELLIPSIS, ellipsis_style));
+    Line::from(spans).style(style)
 }
 
-const SHORTCUTS: &[ShortcutDescriptor] = &[
-    ShortcutDescriptor {
-        id: ShortcutId::Commands,
-        bindings: &[ShortcutBinding {
-            key: key_hint::plain(KeyCode::Char('/')),
-            condition: DisplayCondition::Always,
-        }],
-        prefix: "",
-        label: " for commands",
-    },
-    ShortcutDescriptor {
-        id: ShortcutId::InsertNewline,
-        bindings: &[
-            ShortcutBinding {
-                key: key_hint::shift(KeyCode::Enter),
-                condition: DisplayCondition::WhenShiftEnterHint,
-            },
-            ShortcutBinding {
-                key: key_hint::ctrl(KeyCode::Char('j')),
-                condition: DisplayCondition::WhenNotShiftEnterHint,
-            },
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    let mut used = 0;
+    text.chars()
+        .take_while(|ch| {
+            used += ch.width().unwrap_or(0);
+            used <= max_width
+        })
+        .collect()
+}
+
+fn footer_lines(props: &FooterProps, width: u16, now: Instant) -> Vec<Line<'static>> {
+    // Show the context indicator right-aligned opposite the primary hint
+    // (e.g., "? for shortcuts"), or after it when narrow. Keep it visible even
+    // when typing (i.e., when the shortcut hint is hidden). Hide it only for
+    // the multi-line ShortcutOverlay.
+    match &props.mode {
+        FooterMode::CtrlCReminder { expires_at } => {
+            if notification_expired(*expires_at, now) {
+                vec![shortcut_summary_line(props, width, now)]
+            } else {
+                vec![ctrl_c_reminder_line(CtrlCReminderState {
+                    is_task_running: props.is_task_running,
+                    remaining: expires_at.map(|deadline| deadline.saturating_duration_since(now)),
+                })]
+            }
+        }
+        FooterMode::Notification {
+            message,
+            severity,
+            expires_at,
+        } => {
+            if notification_expired(*expires_at, now) {
+                vec![shortcut_summary_line(props, width, now)]
+            } else {
+                vec![notification_line(message, *severity)]
+            }
+        }
+        FooterMode::Progress { label, percent } => vec![progress_line(label, *percent)],
+        FooterMode::ShortcutSummary => vec![shortcut_summary_line(props, width, now)],
+        FooterMode::CompactShortcuts(state) => vec![compact_shortcuts_line(props, *state)],
+        FooterMode::ShortcutOverlay(state) => {
+            overlay_page(overlay_content(props, *state, width), state.page)
+        }
+        FooterMode::EscHint { backtrack_armed } => vec![esc_hint_line(*backtrack_armed)],
+        FooterMode::ContextOnly => vec![context_window_line(props, now)],
+        FooterMode::Paused => vec![paused_line()],
+        FooterMode::AwaitingApproval => vec![awaiting_approval_line()],
+        FooterMode::Idle => vec![idle_line(props)],
+        FooterMode::Reconnecting(state) => vec![reconnecting_line(*state, now)],
+        FooterMode::Empty { keep_row } => {
+            if *keep_row {
+                vec![Line::default()]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CtrlCReminderState {
+    is_task_running: bool,
+    remaining: Option<Duration>,
+}
+
+/// "? for shortcuts" on the left with the context indicator (and the model,
+/// when it fits) right-aligned. Too narrow for both with a gap, it falls back
+/// to the context indicator followed by the hint.
+fn shortcut_summary_line(props: &FooterProps, width: u16, now: Instant) -> Line<'static> {
+    let available = usize::from(width).saturating_sub(props.indent());
+    let hint = Line::from(vec![
+        key_hint::plain(KeyCode::Char('?')).into(),
+        " for shortcuts".dim(),
+    ]);
+    let context = context_window_line(props, now);
+
+    if hint.width() + RIGHT_ALIGN_MIN_GAP + context.width() <= available {
+        let mut right = Line::from("");
+        let room = available
+            .saturating_sub(hint.width() + RIGHT_ALIGN_MIN_GAP + context.width() + " · ".width());
+        if let Some(label) = model_label(props).and_then(|label| fit_label(label, room)) {
+            right.push_span(label.dim());
+            right.push_span(" · ".dim());
+        }
+        right.extend(context.spans);
+        let mut line = hint;
+        push_right_aligned(&mut line, right, available);
+        return line;
+    }
+
+    let mut line = context;
+    line.push_span(" · ".dim());
+    line.extend(hint.spans);
+    let room = available.saturating_sub(line.width() + RIGHT_ALIGN_MIN_GAP);
+    if let Some(label) = model_label(props).and_then(|label| fit_label(label, room)) {
+        push_right_aligned(&mut line, Line::from(label.dim()), available);
+    }
+    line
+}
+
+fn model_label(props: &FooterProps) -> Option<String> {
+    let model = props.model.as_deref()?;
+    Some(match props.reasoning_effort {
+        Some(effort) => format!("{model} · {effort}"),
+        None => model.to_string(),
+    })
+}
+
+/// Fits `label` into `room` columns, cutting it with an ellipsis when needed.
+/// Returns `None` once fewer than six columns remain, where a stub would not
+/// say anything useful.
+fn fit_label(label: String, room: usize) -> Option<String> {
+    const MIN_LABEL_WIDTH: usize = 6;
+    if label.width() <= room {
+        Some(label)
+    } else if room >= MIN_LABEL_WIDTH {
+        Some(format!("{}…", truncate_to_width(&label, room - 1)))
+    } else {
+        None
+    }
+}
+
+/// Pads `line` out to `available` columns and ends it with `segment`. Leaves
+/// the line alone when the segment would come within `RIGHT_ALIGN_MIN_GAP`
+/// columns of the existing content.
+fn push_right_aligned(line: &mut Line<'static>, segment: Line<'static>, available: usize) {
+    let used = line.width() + segment.width();
+    if used + RIGHT_ALIGN_MIN_GAP > available {
+        return;
+    }
+    line.push_span(" ".repeat(available - used));
+    line.extend(segment.spans);
+}
+
+/// Glyphs match the transcript's info, warning and error cells.
+fn notification_line(message: &str, severity: NotificationSeverity) -> Line<'static> {
+    match severity {
+        NotificationSeverity::Info => Line::from(format!("• {message}")).dim(),
+        NotificationSeverity::Warn => Line::from(format!("⚠ {message}")).bold(),
+        NotificationSeverity::Error => Line::from(format!("■ {message}")).red().bold(),
+    }
+}
+
+fn progress_line(label: &str, percent: u8) -> Line<'static> {
+    let percent = percent.min(100);
+    let filled = usize::from(percent) * PROGRESS_BAR_WIDTH / 100;
+    Line::from(vec![
+        Span::from(format!("{label} ")),
+        "▓".repeat(filled).cyan(),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled).dim(),
+        Span::from(format!(" {percent}%")).dim(),
+    ])
+}
+
+fn ctrl_c_reminder_line(state: CtrlCReminderState) -> Line<'static> {
+    let action = if state.is_task_running {
+        "interrupt"
+    } else {
+        "quit"
+    };
+    let mut line = Line::from(vec![
+        key_hint::ctrl(KeyCode::Char('c')).into(),
+        format!(" again to {action}").into(),
+    ]);
+    if let Some(remaining) = state.remaining {
+        line.push_span(reminder_countdown_span(remaining));
+    }
+    line.dim()
+}
+
+/// When the countdown ending at `expires_at` next shows a different number:
+/// the next whole second it crosses, or the expiry itself in its last second.
+/// `None` once it has expired.
+pub(crate) fn next_countdown_tick(expires_at: Instant, now: Instant) -> Option<Instant> {
+    let remaining = expires_at.saturating_duration_since(now);
+    if remaining.is_zero() {
+        return None;
+    }
+    let shown_secs = remaining.as_millis().div_ceil(1000) as u64;
+    Some(expires_at - Duration::from_secs(shown_secs - 1))
+}
+
+fn reminder_countdown_span(remaining: Duration) -> Span<'static> {
+    // Round up so the countdown reads "(1s)" rather than "(0s)" in its final
+    // second.
+    let secs = remaining.as_millis().div_ceil(1000);
+    Span::from(format!(" ({secs}s)"))
+}
+
+fn esc_hint_line(esc_backtrack_hint: bool) -> Line<'static> {
+    let esc = key_hint::plain(KeyCode::Esc);
+    if esc_backtrack_hint {
+        Line::from(vec![esc.into(), " again to edit previous message".into()]).dim()
+    } else {
+        Line::from(vec![
+            key_hint::chord(esc, esc).into(),
+            " to edit previous message".into(),
+        ])
+        .dim()
+    }
+}
+
+fn paused_line() -> Line<'static> {
+    Line::from(vec![
+        "paused · ".into(),
+        key_hint::plain(KeyCode::Enter).into(),
+        " to resume".into(),
+    ])
+    .dim()
+}
+
+fn awaiting_approval_line() -> Line<'static> {
+    Line::from(vec![
+        "⏸ waiting for approval · ".into(),
+        key_hint::plain(KeyCode::Char('y')).into(),
+        " to approve · ".into(),
+        key_hint::plain(KeyCode::Char('n')).into(),
+        " to deny · ".into(),
+        key_hint::plain(KeyCode::Esc).into(),
+        " to cancel".into(),
+    ])
+    .dim()
+}
+
+fn reconnecting_line(state: ReconnectState, now: Instant) -> Line<'static> {
+    let mut line = Line::from(vec![
+        spinner_at(now),
+        format!(
+            " reconnecting… attempt {}/{}",
+            state.attempt, state.max_attempts
+        )
+        .into(),
+    ]);
+    if let Some(countdown) = retry_countdown_span(state, now) {
+        line.push_span(" · ".dim());
+        line.push_span(countdown);
+    }
+    line
+}
+
+fn retry_countdown_span(state: ReconnectState, now: Instant) -> Option<Span<'static>> {
+    let remaining = state
+        .next_retry_at?
+        .saturating_duration_since(now)
+        .as_millis();
+    (remaining > 0).then(|| format!("retrying in {}s", remaining.div_ceil(1000)).dim())
+}
+
+/// Just the remaining context. Warnings keep their emphasis so a nearly full
+/// window is not hidden by the quieter footer.
+fn idle_line(props: &FooterProps) -> Line<'static> {
+    let percent = props.context_window_percent.unwrap_or(100);
+    Line::from(vec![context_percent_span(percent), " context left".dim()])
+}
+
+/// Shortcuts named on the compact line, in order, with their short labels.
+/// Keys come from `SHORTCUTS` so both views stay in sync.
+const COMPACT_SHORTCUTS: &[(ShortcutId, &str)] = &[
+    (ShortcutId::Commands, " commands"),
+    (ShortcutId::FilePaths, " files"),
+];
+
+fn compact_shortcuts_line(props: &FooterProps, state: ShortcutsState) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (id, label) in COMPACT_SHORTCUTS {
+        let key = SHORTCUTS
+            .iter()
+            .find(|descriptor| descriptor.id == *id)
+            .and_then(|descriptor| descriptor.key_for(props, state));
+        if let Some(key) = key {
+            spans.extend([key.into(), Span::from(*label), " · ".into()]);
+        }
+    }
+    spans.extend([key_hint::plain(KeyCode::Char('?')).into(), " more".into()]);
+    Line::from(spans).dim()
+}
+
+/// One row of the shortcut overlay.
+#[derive(Debug)]
+struct OverlayRow {
+    line: Line<'static>,
+    /// Group headers are never left at the bottom of a page.
+    is_header: bool,
+    /// The row holds the selected entry.
+    selected: bool,
+}
+
+impl OverlayRow {
+    fn entry(line: Line<'static>) -> Self {
+        Self {
+            line,
+            is_header: false,
+            selected: false,
+        }
+    }
+}
+
+/// Every row of the overlay for `state`, before it is split into pages.
+fn overlay_content(props: &FooterProps, state: ShortcutsState, width: u16) -> Vec<OverlayRow> {
+    let available = usize::from(width).saturating_sub(props.indent());
+    let mut rows = shortcut_overlay_lines(props, state, available);
+    if let Some(profile) = &props.active_profile {
+        rows.push(OverlayRow::entry(
+            Line::from(format!("profile: {profile}")).dim(),
+        ));
+    }
+    rows
+}
+
+/// Splits overlay rows into pages of at most `OVERLAY_MAX_ROWS` rows. Rows
+/// that fit are a single page; otherwise every page gives up its last row to
+/// the page indicator.
+fn overlay_pages(rows: Vec<OverlayRow>) -> Vec<Vec<OverlayRow>> {
+    if rows.len() <= OVERLAY_MAX_ROWS {
+        return vec![rows];
+    }
+    let per_page = OVERLAY_MAX_ROWS - 1;
+    let mut pages = Vec::new();
+    let mut page: Vec<OverlayRow> = Vec::with_capacity(per_page);
+    let mut rows = rows.into_iter().peekable();
+    while let Some(row) = rows.next() {
+        page.push(row);
+        if page.len() == per_page && rows.peek().is_some() {
+            // Carry a trailing header over to the page with its entries.
+            let carried = match page.last() {
+                Some(last) if last.is_header && page.len() > 1 => page.pop(),
+                _ => None,
+            };
+            pages.push(std::mem::take(&mut page));
+            page.extend(carried);
+        }
+    }
+    if !page.is_empty() {
+        pages.push(page);
+    }
+    pages
+}
+
+/// Pages the overlay in `props` spans at `width`; 1 in any other mode.
+pub(crate) fn overlay_page_count(props: &FooterProps, width: u16) -> usize {
+    match &props.mode {
+        FooterMode::ShortcutOverlay(state) => {
+            overlay_pages(overlay_content(props, *state, width)).len()
+        }
+        _ => 1,
+    }
+}
+
+/// Page of the overlay in `props` that shows its selected entry at `width`,
+/// if an entry is selected.
+pub(crate) fn overlay_selection_page(props: &FooterProps, width: u16) -> Option<usize> {
+    match &props.mode {
+        FooterMode::ShortcutOverlay(state) => overlay_pages(overlay_content(props, *state, width))
+            .iter()
+            .position(|page| page.iter().any(|row| row.selected)),
+        _ => None,
+    }
+}
+
+/// The rows of page `page` followed by "page 1/2 · ? for more", or every row
+/// when they fit on one page. A page past the end (the terminal grew) shows
+/// the last one.
+fn overlay_page(rows: Vec<OverlayRow>, page: usize) -> Vec<Line<'static>> {
+    let mut pages: Vec<Vec<Line<'static>>> = overlay_pages(rows)
+        .into_iter()
+        .map(|page| page.into_iter().map(|row| row.line).collect())
+        .collect();
+    let total = pages.len();
+    if total == 1 {
+        return pages.remove(0);
+    }
+    let page = page.min(total - 1);
+    let action = if page + 1 < total {
+        " for more"
+    } else {
+        " to close"
+    };
+    let mut lines = pages.swap_remove(page);
+    lines.push(
+        Line::from(vec![
+            format!("page {}/{total} · ", page + 1).into(),
+            key_hint::plain(KeyCode::Char('?')).into(),
+            action.into(),
+        ])
+        .dim(),
+    );
+    lines
+}
+
+/// The overlay entries that apply to `props` and `state`, one list per group
+/// in overlay order. Groups with nothing to show are left out.
+fn overlay_groups(
+    props: &FooterProps,
+    state: ShortcutsState,
+) -> Vec<(ShortcutGroup, Vec<(ShortcutId, Line<'static>)>)> {
+    ShortcutGroup::ALL
+        .into_iter()
+        .map(|group| {
+            let entries = SHORTCUTS
+                .iter()
+                .filter(|descriptor| descriptor.group == group)
+                .filter_map(|descriptor| {
+                    Some((descriptor.id, descriptor.overlay_entry(props, state)?))
+                })
+                .collect::<Vec<_>>();
+            (group, entries)
+        })
+        .filter(|(_, entries)| !entries.is_empty())
+        .collect()
+}
+
+/// Lists the overlay entries that apply to `props` and `state` under a header
+/// per group, each group laid out in as many columns as fit in `available`
+/// cells.
+fn shortcut_overlay_lines(
+    props: &FooterProps,
+    state: ShortcutsState,
+    available: usize,
+) -> Vec<OverlayRow> {
+    let mut rows = Vec::new();
+    for (group, entries) in overlay_groups(props, state) {
+        rows.push(OverlayRow {
+            line: Line::from(group.title()).dim().bold(),
+            is_header: true,
+            selected: false,
+        });
+        rows.extend(overlay_grid(&entries, available, state.selected));
+    }
+    rows
+}
+
+/// The rows of the overlay in `props` in `available` cells, all on one page
+/// and without the footer's indent; empty in any other mode. `/shortcuts`
+/// prints these into the transcript.
+pub(crate) fn shortcut_reference_lines(
+    props: &FooterProps,
+    available: usize,
+) -> Vec<Line<'static>> {
+    let FooterMode::ShortcutOverlay(state) = &props.mode else {
+        return Vec::new();
+    };
+    shortcut_overlay_lines(props, *state, available)
+        .into_iter()
+        .map(|row| row.line)
+        .collect()
+}
+
+/// Lays out one group's entries in as many columns as fit in `available`,
+/// drawing the `selected` entry in reverse video.
+fn overlay_grid(
+    entries: &[(ShortcutId, Line<'static>)],
+    available: usize,
+    selected: Option<ShortcutId>,
+) -> Vec<OverlayRow> {
+    let config = grid_config(entries, available);
+    let cells: Vec<(Option<ShortcutId>, Line<'static>)> = grid_cells(entries, config.columns)
+        .into_iter()
+        .map(|(id, line)| match id {
+            Some(id) if Some(id) == selected => {
+                let spans = line.spans.into_iter().map(Stylize::reversed);
+                (Some(id), Line::from(spans.collect::<Vec<_>>()))
+            }
+            _ => (id, line),
+        })
+        .collect();
+
+    // Which drawn rows hold the selection: one per grid row, or one per
+    // wrapped line in a single column.
+    let mut lines = Vec::new();
+    let mut selected_rows = Vec::new();
+    if config.columns == 1 {
+        // Even a single column can be wider than the footer; wrap the entries
+        // that do not fit instead of letting the terminal clip them.
+        for (id, line) in cells {
+            let wrapped = wrap_entries(&[line], available);
+            selected_rows.extend(std::iter::repeat_n(
+                id.is_some() && id == selected,
+                wrapped.len(),
+            ));
+            lines.extend(wrapped);
+        }
+    } else {
+        for row in cells.chunks(config.columns) {
+            selected_rows.push(row.iter().any(|(id, _)| id.is_some() && *id == selected));
+        }
+        lines = cells.into_iter().map(|(_, line)| line).collect();
+    }
+    build_columns(lines, &config, available)
+        .into_iter()
+        .zip(selected_rows)
+        .map(|(line, selected)| OverlayRow {
+            line,
+            is_header: false,
+            selected,
+        })
+        .collect()
+}
+
+/// The widest grid `entries` fit in within `available` cells, down to a
+/// single column.
+fn grid_config(entries: &[(ShortcutId, Line<'static>)], available: usize) -> ColumnConfig {
+    let widest = ColumnConfig::default();
+    (2..=widest.columns)
+        .rev()
+        .map(|columns| ColumnConfig {
+            columns,
+            ..widest.clone()
+        })
+        .find(|config| {
+            let lines: Vec<Line<'static>> = grid_cells(entries, config.columns)
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect();
+            config.row_width(&column_widths(&lines, config.columns)) <= available
+        })
+        .unwrap_or(ColumnConfig {
+            columns: 1,
+            ..widest
+        })
+}
+
+/// The cells of a `columns`-wide grid of `entries` in row order; spacer cells
+/// have no id.
+fn grid_cells(
+    entries: &[(ShortcutId, Line<'static>)],
+    columns: usize,
+) -> Vec<(Option<ShortcutId>, Line<'static>)> {
+    let mut cells: Vec<(Option<ShortcutId>, Line<'static>)> = entries
+        .iter()
+        .map(|(id, line)| (Some(*id), line.clone()))
+        .collect();
+    // In the two-column layout, keep the transcript hint in the right column.
+    if columns == 2
+        && cells.len() % 2 == 1
+        && entries
+            .last()
+            .is_some_and(|(id, _)| *id == ShortcutId::ShowTranscript)
+    {
+        cells.insert(cells.len() - 1, (None, Line::from("")));
+    }
+    cells
+}
+
+/// Arrow key moving the overlay selection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum OverlayMove {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// The overlay's selectable entries as laid out at one width: the rows of
+/// every group's grid, top to bottom, with `None` for spacer cells.
+#[derive(Debug, Default)]
+pub(crate) struct OverlayNavigation {
+    rows: Vec<Vec<Option<ShortcutId>>>,
+}
+
+impl OverlayNavigation {
+    /// Entries of the overlay in `props` at `width`; empty in any other mode.
+    pub(crate) fn new(props: &FooterProps, width: u16) -> Self {
+        let FooterMode::ShortcutOverlay(state) = &props.mode else {
+            return Self::default();
+        };
+        let available = usize::from(width).saturating_sub(props.indent());
+        let mut rows = Vec::new();
+        for (_, entries) in overlay_groups(props, *state) {
+            let columns = grid_config(&entries, available).columns;
+            let ids: Vec<Option<ShortcutId>> = grid_cells(&entries, columns)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            rows.extend(ids.chunks(columns).map(<[_]>::to_vec));
+        }
+        Self { rows }
+    }
+
+    /// Entry ids in display order: row by row, left to right.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub(crate) fn ids(&self) -> impl Iterator<Item = ShortcutId> + '_ {
+        self.rows.iter().flatten().filter_map(|id| *id)
+    }
+
+    /// The entry `direction` moves to from `from`. Left and right step through
+    /// the entries in display order, continuing on the next or previous row;
+    /// up and down keep the column where the row has one. Every move wraps
+    /// around at the edges. Without a selection, right and down start at the
+    /// first entry, left and up at the last.
+    pub(crate) fn step(
+        &self,
+        from: Option<ShortcutId>,
+        direction: OverlayMove,
+    ) -> Option<ShortcutId> {
+        let cells: Vec<(usize, usize, ShortcutId)> = self
+            .rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, ids)| {
+                ids.iter()
+                    .enumerate()
+                    .filter_map(move |(col, id)| Some((row, col, (*id)?)))
+            })
+            .collect();
+        let count = cells.len();
+        let Some(current) = from.and_then(|from| cells.iter().position(|(_, _, id)| *id == from))
+        else {
+            return match direction {
+                OverlayMove::Right | OverlayMove::Down => cells.first(),
+                OverlayMove::Left | OverlayMove::Up => cells.last(),
+            }
+            .map(|(_, _, id)| *id);
+        };
+        let (row, col, _) = cells[current];
+        let target = match direction {
+            OverlayMove::Right => cells[(current + 1) % count],
+            OverlayMove::Left => cells[(current + count - 1) % count],
+            OverlayMove::Up | OverlayMove::Down => {
+                let rows = self.rows.len();
+                let next_row = if direction == OverlayMove::Down {
+                    (row + 1) % rows
+                } else {
+                    (row + rows - 1) % rows
+                };
+                // The rightmost entry at or left of the column, else the
+                // row's first entry.
+                let mut in_row = cells.iter().filter(|(r, _, _)| *r == next_row);
+                in_row
+                    .clone()
+                    .rfind(|(_, c, _)| *c <= col)
+                    .or_else(|| in_row.next())
+                    .copied()
+                    .unwrap_or(cells[current])
+            }
+        };
+        Some(target.2)
+    }
+}
+
+/// Splits each entry wider than `available` over several lines, indenting the
+/// continuation lines so they read as part of the same shortcut.
+fn wrap_entries(entries: &[Line<'static>], available: usize) -> Vec<Line<'static>> {
+    let mut wrapped = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if rendered_width(entry) <= available {
+            wrapped.push(entry.clone());
+        } else {
+            let options = RtOptions::new(available).subsequent_indent(Line::from("  "));
+            push_owned_lines(&word_wrap_line(entry, options), &mut wrapped);
+        }
+    }
+    wrapped
+}
+
+/// Grid used to lay out the shortcut overlay.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ColumnConfig {
+    /// Number of columns; entries fill the grid row by row.
+    columns: usize,
+    /// Upper bound on the spare width added after each column, by index.
+    /// Columns without an entry get none.
+    column_padding: Vec<usize>,
+    /// Minimum blank cells between two columns.
+    column_gap: usize,
+}
+
+impl Default for ColumnConfig {
+    /// The widest overlay layout; `grid_config` drops columns from
+    /// it until the entries fit.
+    fn default() -> Self {
+        Self {
+            columns: 3,
+            column_padding: vec![4, 4],
+            column_gap: 4,
+        }
+    }
+}
+
+impl ColumnConfig {
+    /// Total width of a row with `widths` using only the minimum gaps.
+    fn row_width(&self, widths: &[usize]) -> usize {
+        widths.iter().sum::<usize>() + self.column_gap * widths.len().saturating_sub(1)
+    }
+}
+
+/// Cells `line` occupies once drawn: the sum of each grapheme's width, the way
+/// the buffer places them, so wide (CJK, emoji) and zero-width graphemes line
+/// up regardless of how the spans are split or styled.
+fn rendered_width(line: &Line<'_>) -> usize {
+    line.spans
+        .iter()
+        .flat_map(|span| span.content.graphemes(true))
+        .filter(|grapheme| *grapheme != "\n")
+        .map(UnicodeWidthStr::width)
+        .sum()
+}
+
+/// Widest entry in each column when `entries` are laid out row by row.
+fn column_widths(entries: &[Line<'static>], columns: usize) -> Vec<usize> {
+    let mut widths = vec![0usize; columns];
+    for (idx, entry) in entries.iter().enumerate() {
+        let column = idx % columns;
+        widths[column] = widths[column].max(rendered_width(entry));
+    }
+    widths
+}
+
+/// Lays `entries` out row by row in `config.columns` columns, each column as
+/// wide as its widest entry plus its share of the spare `available` width.
+/// Every entry fills a cell, so callers pass only entries that should be
+/// shown (or deliberate spacers); the last row is padded here when it is
+/// short.
+fn build_columns(
+    entries: Vec<Line<'static>>,
+    config: &ColumnConfig,
+    available: usize,
+) -> Vec<Line<'static>> {
+    let columns = config.columns;
+    if entries.is_empty() || columns == 0 {
+        return Vec::new();
+    }
+
+    let rows = entries.len().div_ceil(columns);
+    let target_len = rows * columns;
+    let mut entries = entries;
+    if entries.len() < target_len {
+        entries.extend(std::iter::repeat_n(
+            Line::from(""),
+            target_len - entries.len(),
+        ));
+    }
+
+    let column_widths = column_widths(&entries, columns);
+    // Spread whatever width is left over between the columns, capped so wide
+    // terminals do not push the columns far apart.
+    let spare = match columns {
+        1 => 0,
+        _ => available.saturating_sub(config.row_width(&column_widths)) / (columns - 1),
+    };
+    let padding = |col: usize| {
+        config
+            .column_padding
+            .get(col)
+            .map_or(0, |&max| spare.min(max))
+    };
+
+    entries
+        .chunks(columns)
+        .map(|chunk| {
+            let mut line = Line::from("");
+            for (col, entry) in chunk.iter().enumerate() {
+                line.extend(entry.spans.clone());
+                if col < columns - 1 {
+                    let fill = column_widths[col].saturating_sub(rendered_width(entry));
+                    let gap = fill + padding(col) + config.column_gap;
+                    line.push_span(Span::from(" ".repeat(gap)));
+                }
+            }
+            line.dim()
+        })
+        .collect()
+}
+
+/// The remaining-context percentage, styled by its urgency band. Shared by
+/// the full and idle lines so both escalate the same way.
+fn context_percent_span(percent: u8) -> Span<'static> {
+    let span = Span::from(format!("{percent}%"));
+    match context_window_urgency(percent) {
+        ContextWindowUrgency::Normal => span.dim(),
+        ContextWindowUrgency::Warning => span.bold(),
+        ContextWindowUrgency::Critical => span.fg(Color::Red).bold(),
+    }
+}
+
+fn context_window_line(props: &FooterProps, now: Instant) -> Line<'static> {
+    let percent = props.context_window_percent.unwrap_or(100);
+    let mut line = Line::from("");
+    if let Some(badge) = props.sandbox_badge {
+        line.push_span(sandbox_badge_span(badge));
+        line.push_span(Span::from(" "));
+    }
+    if let Some(rate) = props.tokens_per_second {
+        line.push_span(Span::from(format!("{rate:.0} tok/s")).dim());
+        line.push_span(" · ".dim());
+    }
+    let urgency = context_window_urgency(percent);
+    line.push_span(context_percent_span(percent));
+    line.push_span(" context left".dim());
+    if let (Some(used), Some(max)) = (props.context_tokens_used, props.context_tokens_max) {
+        line.push_span(
+            format!(
+                " ({} / {} tokens)",
+                format_token_count(used),
+                format_token_count(max)
+            )
+            .dim(),
+        );
+    }
+    if urgency == ContextWindowUrgency::Critical {
+        line.push_span(" · ".dim());
+        line.push_span(Span::from("/compact"));
+        line.push_span(" to free space".dim());
+    }
+    if let Some(elapsed) = task_elapsed_span(props, now) {
+        line.push_span(" · ".dim());
+        line.push_span(elapsed);
+    }
+    if props.is_task_running && props.queued_user_messages > 0 {
+        let count = props.queued_user_messages;
+        let noun = if count == 1 { "message" } else { "messages" };
+        line.push_span(" · ".dim());
+        line.push_span(format!("{count} {noun} queued").dim());
+        line.push_span(" · ".dim());
+        line.push_span(Span::from(key_hint::alt(KeyCode::Up)));
+        line.push_span(" edit".dim());
+    }
+    line
+}
+
+/// Time since the running task started. Skips the first second so a fresh
+/// task does not flash "0.0s".
+fn task_elapsed_span(props: &FooterProps, now: Instant) -> Option<Span<'static>> {
+    let started_at = props.task_started_at.filter(|_| props.is_task_running)?;
+    let elapsed = now.saturating_duration_since(started_at);
+    (elapsed >= Duration::from_secs(1)).then(|| format_task_elapsed(elapsed).dim())
+}
+
+fn sandbox_badge_span(badge: SandboxBadge) -> Span<'static> {
+    match badge {
+        SandboxBadge::ReadOnly => badge.label().dim(),
+        SandboxBadge::Auto => badge.label().cyan(),
+        SandboxBadge::FullAccess => badge.label().red().bold(),
+    }
+}
+
+/// Abbreviates token counts of 10k and above with a `k` or `M` suffix.
+fn format_token_count(tokens: u64) -> String {
+    if tokens < 10_000 {
+        tokens.to_string()
+    } else if tokens < 999_500 {
+        format!("{}k", (tokens as f64 / 1_000.0).round())
+    } else {
+        let millions = format!("{:.1}", tokens as f64 / 1_000_000.0);
+        format!("{}M", millions.trim_end_matches(".0"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ContextWindowUrgency {
+    Normal,
+    Warning,
+    Critical,
+}
+
+fn context_window_urgency(percent: u8) -> ContextWindowUrgency {
+    if percent < CONTEXT_CRITICAL_PERCENT {
+        ContextWindowUrgency::Critical
+    } else if percent < CONTEXT_WARNING_PERCENT {
+        ContextWindowUrgency::Warning
+    } else {
+        ContextWindowUrgency::Normal
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ShortcutId {
+    Commands,
+    InsertNewline,
+    VimModes,
+    FilePaths,
+    PasteImage,
+    EditPrevious,
+    Quit,
+    Interrupt,
+    Compact,
+    ClearContext,
+    ShowTranscript,
+}
+
+const SHORTCUT_ID_COUNT: usize = ShortcutId::ShowTranscript as usize + 1;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ShortcutBinding {
+    key: KeyChord,
+    condition: DisplayCondition,
+}
+
+impl ShortcutBinding {
+    fn matches(&self, props: &FooterProps, state: ShortcutsState) -> bool {
+        self.condition.matches(props, state)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DisplayCondition {
+    Always,
+    WhenShiftEnterHint,
+    WhenNotShiftEnterHint,
+    /// Remaining context is known and at or below the given percentage.
+    WhenContextLow(u8),
+    /// The composer uses the given keymap.
+    WhenKeymap(KeymapStyle),
+    WhenTaskRunning,
+    WhenEscInterrupts,
+    WhenClipboardImages,
+    WhenPreviousMessage,
+    /// Every condition holds; an empty list always matches.
+    All(&'static [DisplayCondition]),
+    /// At least one condition holds; an empty list never matches.
+    #[cfg_attr(not(test), allow(dead_code))]
+    Any(&'static [DisplayCondition]),
+    Not(&'static DisplayCondition),
+}
+
+impl DisplayCondition {
+    fn matches(self, props: &FooterProps, state: ShortcutsState) -> bool {
+        match self {
+            DisplayCondition::Always => true,
+            DisplayCondition::WhenShiftEnterHint => state.use_shift_enter_hint,
+            DisplayCondition::WhenNotShiftEnterHint => !state.use_shift_enter_hint,
+            DisplayCondition::WhenContextLow(threshold) => props
+                .context_window_percent
+                .is_some_and(|percent| percent <= threshold),
+            DisplayCondition::WhenKeymap(keymap) => state.keymap == keymap,
+            DisplayCondition::WhenTaskRunning => state.is_task_running,
+            DisplayCondition::WhenEscInterrupts => state.esc_interrupts,
+            DisplayCondition::WhenClipboardImages => state.clipboard_images_supported,
+            DisplayCondition::WhenPreviousMessage => state.has_previous_message,
+            DisplayCondition::All(conditions) => conditions.iter().all(|c| c.matches(props, state)),
+            DisplayCondition::Any(conditions) => conditions.iter().any(|c| c.matches(props, state)),
+            DisplayCondition::Not(condition) => !condition.matches(props, state),
+        }
+    }
+}
+
+/// Heading the overlay lists a shortcut under.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ShortcutGroup {
+    /// Writing and sending the draft.
+    Composer,
+    /// The running turn, the conversation and the app.
+    Session,
+}
+
+impl ShortcutGroup {
+    /// Overlay order.
+    const ALL: [ShortcutGroup; 2] = [ShortcutGroup::Composer, ShortcutGroup::Session];
+
+    fn title(self) -> &'static str {
+        match self {
+            ShortcutGroup::Composer => "Composer",
+            ShortcutGroup::Session => "Session",
+        }
+    }
+}
+
+struct ShortcutDescriptor {
+    id: ShortcutId,
+    group: ShortcutGroup,
+    bindings: &'static [ShortcutBinding],
+    prefix: &'static str,
+    label: &'static str,
+}
+
+impl ShortcutDescriptor {
+    fn binding_for(
+        &self,
+        props: &FooterProps,
+        state: ShortcutsState,
+    ) -> Option<&'static ShortcutBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.matches(props, state))
+    }
+
+    /// Key to advertise for `state`: the rebound key if there is one,
+    /// otherwise the default whose condition holds.
+    fn key_for(&self, props: &FooterProps, state: ShortcutsState) -> Option<KeyChord> {
+        let binding = self.binding_for(props, state)?;
+        Some(state.table.remapped(self.id).unwrap_or(binding.key))
+    }
+
+    fn overlay_entry(&self, props: &FooterProps, state: ShortcutsState) -> Option<Line<'static>> {
+        let key = self.key_for(props, state)?;
+        // The overlay dims whole rows, so a highlighted key opts back out.
+        let key_span = |key: Span<'static>| {
+            if state.highlight_keys {
+                key.not_dim()
+            } else {
+                key
+            }
+        };
+        let mut line = Line::from(vec![self.prefix.into(), key_span(key.into())]);
+        match self.id {
+            // One esc has been pressed already, so only the last key is left.
+            ShortcutId::EditPrevious if state.esc_backtrack_hint => {
+                line = Line::from(vec![
+                    self.prefix.into(),
+                    key_span(key.first().into()),
+                    " again to edit previous message".into(),
+                ]);
+            }
+            ShortcutId::VimModes => line.extend(vec![
+                " normal mode · ".into(),
+                key_span(key_hint::plain(KeyCode::Char('i')).into()),
+                " insert mode".into(),
+            ]),
+            _ => line.push_span(self.label),
+        };
+        Some(line)
+    }
+}
+
+const SHORTCUTS: &[ShortcutDescriptor] = &[
+    ShortcutDescriptor {
+        id: ShortcutId::Commands,
+        group: ShortcutGroup::Composer,
+        bindings: &[ShortcutBinding {
+            key: KeyChord::single(key_hint::plain(KeyCode::Char('/'))),
+            condition: DisplayCondition::Always,
+        }],
+        prefix: "",
+        label: " for commands",
+    },
+    ShortcutDescriptor {
+        id: ShortcutId::InsertNewline,
+        group: ShortcutGroup::Composer,
+        bindings: &[
+            // Normal mode opens a line below, as in vim.
+            ShortcutBinding {
+                key: KeyChord::single(key_hint::plain(KeyCode::Char('o'))),
+                condition: DisplayCondition::WhenKeymap(KeymapStyle::Vim),
+            },
+            ShortcutBinding {
+                key: KeyChord::single(key_hint::shift(KeyCode::Enter)),
+                condition: DisplayCondition::WhenShiftEnterHint,
+            },
+            ShortcutBinding {
+                key: KeyChord::single(key_hint::ctrl(KeyCode::Char('j'))),
+                condition: DisplayCondition::WhenNotShiftEnterHint,
+            },
         ],
         prefix: "",
         label: " for newline",
     },
+    ShortcutDescriptor {
+        id: ShortcutId::VimModes,
+        group: ShortcutGroup::Composer,
+        bindings: &[ShortcutBinding {
+            key: KeyChord::single(key_hint::plain(KeyCode::Esc)),
+            condition: DisplayCondition::WhenKeymap(KeymapStyle::Vim),
+        }],
+        prefix: "",
+        label: "",
+    },
     ShortcutDescriptor {
         id: ShortcutId::FilePaths,
+        group: ShortcutGroup::Composer,
         bindings: &[ShortcutBinding {
-            key: key_hint::plain(KeyCode::Char('@')),
+            key: KeyChord::single(key_hint::plain(KeyCode::Char('@'))),
             condition: DisplayCondition::Always,
         }],
         prefix: "",
@@ -335,35 +2047,83 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
     ShortcutDescriptor {
         id: ShortcutId::PasteImage,
+        group: ShortcutGroup::Composer,
         bindings: &[ShortcutBinding {
-            key: key_hint::ctrl(KeyCode::Char('v')),
-            condition: DisplayCondition::Always,
+            key: KeyChord::single(key_hint::ctrl(KeyCode::Char('v'))),
+            condition: DisplayCondition::WhenClipboardImages,
         }],
         prefix: "",
         label: " to paste images",
     },
     ShortcutDescriptor {
         id: ShortcutId::EditPrevious,
+        group: ShortcutGroup::Composer,
         bindings: &[ShortcutBinding {
-            key: key_hint::plain(KeyCode::Esc),
-            condition: DisplayCondition::Always,
+            key: key_hint::chord(key_hint::plain(KeyCode::Esc), key_hint::plain(KeyCode::Esc)),
+            // While a task runs, esc interrupts it instead of backtracking.
+            condition: DisplayCondition::All(&[
+                DisplayCondition::WhenPreviousMessage,
+                DisplayCondition::Not(&DisplayCondition::WhenTaskRunning),
+            ]),
         }],
         prefix: "",
-        label: "",
+        label: " to edit previous message",
+    },
+    ShortcutDescriptor {
+        id: ShortcutId::Interrupt,
+        group: ShortcutGroup::Session,
+        bindings: &[
+            ShortcutBinding {
+                key: KeyChord::single(key_hint::plain(KeyCode::Esc)),
+                condition: DisplayCondition::All(&[
+                    DisplayCondition::WhenTaskRunning,
+                    DisplayCondition::WhenEscInterrupts,
+                ]),
+            },
+            ShortcutBinding {
+                key: KeyChord::single(key_hint::ctrl(KeyCode::Char('c'))),
+                condition: DisplayCondition::WhenTaskRunning,
+            },
+        ],
+        prefix: "",
+        label: " to interrupt",
     },
     ShortcutDescriptor {
         id: ShortcutId::Quit,
+        group: ShortcutGroup::Session,
         bindings: &[ShortcutBinding {
-            key: key_hint::ctrl(KeyCode::Char('c')),
-            condition: DisplayCondition::Always,
+            key: KeyChord::single(key_hint::ctrl(KeyCode::Char('c'))),
+            // While a task runs, ctrl + c interrupts it rather than exiting.
+            condition: DisplayCondition::Not(&DisplayCondition::WhenTaskRunning),
         }],
         prefix: "",
         label: " to exit",
     },
+    ShortcutDescriptor {
+        id: ShortcutId::Compact,
+        group: ShortcutGroup::Session,
+        bindings: &[ShortcutBinding {
+            key: KeyChord::single(key_hint::plain(KeyCode::Char('/'))),
+            condition: DisplayCondition::WhenContextLow(CONTEXT_WARNING_PERCENT),
+        }],
+        prefix: "",
+        label: "compact to free context",
+    },
+    ShortcutDescriptor {
+        id: ShortcutId::ClearContext,
+        group: ShortcutGroup::Session,
+        bindings: &[ShortcutBinding {
+            key: KeyChord::single(key_hint::ctrl(KeyCode::Char('k'))),
+            condition: DisplayCondition::WhenContextLow(CONTEXT_CLEAR_PERCENT),
+        }],
+        prefix: "",
+        label: " to start a new chat",
+    },
     ShortcutDescriptor {
         id: ShortcutId::ShowTranscript,
+        group: ShortcutGroup::Session,
         bindings: &[ShortcutBinding {
-            key: key_hint::ctrl(KeyCode::Char('t')),
+            key: KeyChord::single(key_hint::DEFAULT_TRANSCRIPT_KEY),
             condition: DisplayCondition::Always,
         }],
         prefix: "",
@@ -371,102 +2131,1928 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
 ];
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use insta::assert_snapshot;
-    use ratatui::Terminal;
-    use ratatui::backend::TestBackend;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shimmer::with_animation_start;
+    use crate::ui_consts::IDLE_TIMEOUT_SECS;
+    use codex_core::config_types::KeyHintModifiers;
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Modifier;
+    use ratatui::style::Style;
+
+    fn snapshot_footer(name: &str, props: FooterProps) {
+        snapshot_footer_with_width(name, 80, props);
+    }
+
+    fn snapshot_footer_with_width(name: &str, width: u16, props: FooterProps) {
+        snapshot_footer_at(name, width, props, Instant::now());
+    }
+
+    /// Renders as of `now`, so props holding instants derived from it draw the
+    /// same countdowns and elapsed times on every run.
+    fn snapshot_footer_at(name: &str, width: u16, props: FooterProps, now: Instant) {
+        let height = footer_height(&props, width);
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|f| {
+                let area = Rect::new(0, 0, f.area().width, height);
+                render_footer(area, f.buffer_mut(), &props, now);
+            })
+            .unwrap();
+        assert_snapshot!(name, terminal.backend());
+    }
+
+    #[test]
+    fn footer_snapshots() {
+        let now = Instant::now();
+        snapshot_footer("footer_shortcuts_default", FooterProps::builder().build());
+
+        snapshot_footer(
+            "footer_shortcuts_shift_and_esc",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    use_shift_enter_hint: true,
+                    esc_backtrack_hint: true,
+                    ..session_shortcuts()
+                }))
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_ctrl_c_quit_idle",
+            FooterProps::builder()
+                .mode(FooterMode::CtrlCReminder { expires_at: None })
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_ctrl_c_quit_running",
+            FooterProps::builder()
+                .mode(FooterMode::CtrlCReminder { expires_at: None })
+                .task_running(true)
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_esc_hint_idle",
+            FooterProps::builder()
+                .mode(FooterMode::EscHint {
+                    backtrack_armed: false,
+                })
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_esc_hint_primed",
+            FooterProps::builder()
+                .mode(FooterMode::EscHint {
+                    backtrack_armed: true,
+                })
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_shortcuts_context_running",
+            FooterProps::builder()
+                .task_running(true)
+                .context_window_percent(72)
+                .build(),
+        );
+
+        snapshot_footer(
+            "footer_shortcuts_token_rate_running",
+            FooterProps::builder()
+                .task_running(true)
+                .context_window_percent(72)
+                .tokens_per_second(42.3)
+                .build(),
+        );
+
+        snapshot_footer_with_width(
+            "footer_shortcuts_token_rate_narrow",
+            40,
+            FooterProps::builder()
+                .task_running(true)
+                .context_window_percent(72)
+                .tokens_per_second(42.3)
+                .build(),
+        );
+
+        for (name, severity, message) in [
+            (
+                "footer_notification_info",
+                NotificationSeverity::Info,
+                "Transcript copied",
+            ),
+            (
+                "footer_notification_warn",
+                NotificationSeverity::Warn,
+                "Failed to paste image from clipboard",
+            ),
+            (
+                "footer_notification_error",
+                NotificationSeverity::Error,
+                "Failed to save default model",
+            ),
+        ] {
+            snapshot_footer_at(
+                name,
+                80,
+                FooterProps::builder()
+                    .mode(FooterMode::Notification {
+                        message: message.into(),
+                        severity,
+                        expires_at: Some(now + Duration::from_secs(60)),
+                    })
+                    .build(),
+                now,
+            );
+        }
+
+        snapshot_footer(
+            "footer_notification_without_expiry",
+            FooterProps::builder()
+                .mode(FooterMode::Notification {
+                    message: "Transcript copied".into(),
+                    severity: NotificationSeverity::Info,
+                    expires_at: None,
+                })
+                .task_running(true)
+                .context_window_percent(72)
+                .build(),
+        );
+
+        snapshot_footer_at(
+            "footer_notification_expired",
+            80,
+            FooterProps::builder()
+                .mode(FooterMode::Notification {
+                    message: "Failed to paste image from clipboard".into(),
+                    severity: NotificationSeverity::Warn,
+                    expires_at: Some(now),
+                })
+                .context_window_percent(72)
+                .build(),
+            now,
+        );
+    }
+
+    #[test]
+    fn sandbox_badge_snapshots() {
+        for (name, badge) in [
+            ("read_only", SandboxBadge::ReadOnly),
+            ("auto", SandboxBadge::Auto),
+            ("full_access", SandboxBadge::FullAccess),
+        ] {
+            let props = FooterProps::builder()
+                .sandbox_badge(badge)
+                .context_window_percent(72)
+                .build();
+            snapshot_footer(&format!("footer_sandbox_{name}"), props.clone());
+            snapshot_footer(
+                &format!("footer_sandbox_{name}_running"),
+                FooterProps {
+                    mode: FooterMode::ContextOnly,
+                    is_task_running: true,
+                    ..props.clone()
+                },
+            );
+        }
+
+        // The overlay and ctrl + c reminder replace the line that carries it.
+        let props = FooterProps::builder()
+            .sandbox_badge(SandboxBadge::FullAccess)
+            .build();
+        for mode in [
+            FooterMode::ShortcutOverlay(session_shortcuts()),
+            FooterMode::CtrlCReminder { expires_at: None },
+        ] {
+            let lines = footer_lines(
+                &FooterProps {
+                    mode,
+                    ..props.clone()
+                },
+                80,
+                Instant::now(),
+            );
+            assert!(
+                lines
+                    .iter()
+                    .all(|line| !line.to_string().contains("[full-access]"))
+            );
+        }
+    }
+
+    #[test]
+    fn ctrl_c_reminder_countdown_snapshot() {
+        let now = Instant::now();
+        snapshot_footer_at(
+            "footer_ctrl_c_quit_countdown",
+            80,
+            FooterProps::builder()
+                .mode(FooterMode::CtrlCReminder {
+                    expires_at: Some(now + Duration::from_secs(2)),
+                })
+                .build(),
+            now,
+        );
+    }
+
+    #[test]
+    fn ctrl_c_reminder_reverts_once_expired() {
+        let now = Instant::now();
+        let props = FooterProps::builder()
+            .mode(FooterMode::CtrlCReminder {
+                expires_at: Some(now),
+            })
+            .build();
+        let summary = FooterProps {
+            mode: FooterMode::ShortcutSummary,
+            ..props.clone()
+        };
+        assert_eq!(
+            footer_lines(&props, 80, now),
+            footer_lines(&summary, 80, now)
+        );
+    }
+
+    #[test]
+    fn queued_messages_snapshots() {
+        let props = FooterProps::builder()
+            .task_running(true)
+            .context_window_percent(72)
+            .build();
+        for count in [1, 3] {
+            snapshot_footer(
+                &format!("footer_queued_messages_{count}"),
+                FooterProps {
+                    queued_user_messages: count,
+                    ..props.clone()
+                },
+            );
+        }
+        snapshot_footer(
+            "footer_queued_messages_idle",
+            FooterProps {
+                is_task_running: false,
+                queued_user_messages: 2,
+                ..props.clone()
+            },
+        );
+
+        // Nothing queued: no count and no edit hint.
+        let text = footer_lines(&props, 80, Instant::now())
+            .iter()
+            .map(ToString::to_string)
+            .collect::<String>();
+        assert!(!text.contains("queued"), "{text}");
+        assert!(!text.contains(" edit"), "{text}");
+    }
+
+    #[test]
+    fn model_label_snapshots() {
+        let props = FooterProps::builder()
+            .context_window_percent(72)
+            .model("gpt-5-codex".to_string())
+            .reasoning_effort(ReasoningEffortConfig::Medium)
+            .build();
+        snapshot_footer("footer_model_wide", props.clone());
+        snapshot_footer(
+            "footer_model_without_effort",
+            FooterProps {
+                reasoning_effort: None,
+                ..props.clone()
+            },
+        );
+        // Too narrow for the whole label, so it is cut with an ellipsis.
+        snapshot_footer_with_width("footer_model_narrow", 50, props.clone());
+        snapshot_footer_with_width(
+            "footer_model_long_width_60",
+            60,
+            FooterProps {
+                model: Some("gpt-5-codex-2025-09-15-preview-long-context".to_string()),
+                reasoning_effort: Some(ReasoningEffortConfig::High),
+                ..props.clone()
+            },
+        );
+        // With barely any room left the label is dropped rather than shown as
+        // a stub.
+        snapshot_footer_with_width("footer_model_too_narrow", 42, props);
+    }
+
+    #[test]
+    fn task_elapsed_snapshots() {
+        let now = Instant::now();
+        let started_at = |secs| {
+            now.checked_sub(Duration::from_secs(secs))
+                .expect("instant in the past")
+        };
+        let props = FooterProps::builder()
+            .task_running(true)
+            .context_window_percent(72)
+            .task_started_at(started_at(102))
+            .build();
+        snapshot_footer_at("footer_task_elapsed_minutes", 80, props.clone(), now);
+        snapshot_footer_at(
+            "footer_task_elapsed_hours",
+            80,
+            FooterProps {
+                task_started_at: Some(started_at(3 * 3600 + 7 * 60 + 9)),
+                ..props.clone()
+            },
+            now,
+        );
+        snapshot_footer_at("footer_task_elapsed_narrow", 40, props.clone(), now);
+        snapshot_footer_at(
+            "footer_task_elapsed_idle",
+            80,
+            FooterProps {
+                is_task_running: false,
+                ..props
+            },
+            now,
+        );
+    }
+
+    #[test]
+    fn format_task_elapsed_by_magnitude() {
+        assert_eq!(format_task_elapsed(Duration::from_millis(4_250)), "4.2s");
+        assert_eq!(format_task_elapsed(Duration::from_millis(59_900)), "59.9s");
+        assert_eq!(format_task_elapsed(Duration::from_secs(60)), "1m 00s");
+        assert_eq!(format_task_elapsed(Duration::from_secs(102)), "1m 42s");
+        assert_eq!(format_task_elapsed(Duration::from_secs(3599)), "59m 59s");
+        assert_eq!(
+            format_task_elapsed(Duration::from_secs(3600 + 3 * 60)),
+            "1h 03m"
+        );
+        assert_eq!(
+            format_task_elapsed(Duration::from_secs(36_000 + 61)),
+            "10h 01m"
+        );
+    }
+
+    #[test]
+    fn shortcut_overlay_adapts_to_width() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(session_shortcuts()))
+            .build();
+        snapshot_footer_with_width("footer_shortcuts_overlay_width_40", 40, props.clone());
+        // Too narrow for some entries even in one column: they wrap.
+        snapshot_footer_with_width("footer_shortcuts_overlay_width_30", 30, props.clone());
+        // Two columns only just fit here, so the padding between them shrinks.
+        snapshot_footer_with_width("footer_shortcuts_overlay_width_66", 66, props.clone());
+        snapshot_footer_with_width("footer_shortcuts_overlay_width_80", 80, props.clone());
+        snapshot_footer_with_width("footer_shortcuts_overlay_width_160", 160, props);
+    }
+
+    #[test]
+    fn context_window_snapshots() {
+        for (band, percent) in [
+            ("normal", Some(72)),
+            ("warning", Some(20)),
+            ("critical", Some(5)),
+            ("unknown", None),
+        ] {
+            snapshot_footer(
+                &format!("footer_context_window_{band}"),
+                FooterProps {
+                    context_window_percent: percent,
+                    ..FooterProps::builder()
+                        .mode(FooterMode::ContextOnly)
+                        .task_running(true)
+                        .build()
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn context_window_percent_is_colored_by_headroom() {
+        let percent_style = |percent| {
+            context_window_line(
+                &FooterProps::builder()
+                    .context_window_percent(percent)
+                    .build(),
+                Instant::now(),
+            )
+            .spans
+            .into_iter()
+            .find(|span| span.content.ends_with('%'))
+            .map(|span| span.style)
+        };
+        assert_eq!(
+            percent_style(5),
+            Some(Style::default().fg(Color::Red).bold())
+        );
+        assert_eq!(percent_style(20), Some(Style::default().bold()));
+        assert_eq!(percent_style(72), Some(Style::default().dim()));
+    }
+
+    #[test]
+    fn context_window_token_counts() {
+        let props = FooterProps::builder()
+            .task_running(true)
+            .context_window_percent(72)
+            .context_tokens(93_412, 128_000)
+            .build();
+        snapshot_footer("footer_context_window_token_counts", props.clone());
+        snapshot_footer(
+            "footer_context_window_token_counts_critical",
+            FooterProps {
+                context_window_percent: Some(4),
+                context_tokens_used: Some(1_234_567),
+                context_tokens_max: Some(1_280_000),
+                ..props
+            },
+        );
+    }
+
+    #[test]
+    fn format_token_count_abbreviates_large_values() {
+        assert_eq!(format_token_count(0), "0");
+        assert_eq!(format_token_count(9_999), "9999");
+        assert_eq!(format_token_count(10_000), "10k");
+        assert_eq!(format_token_count(93_412), "93k");
+        assert_eq!(format_token_count(128_000), "128k");
+        assert_eq!(format_token_count(999_499), "999k");
+        assert_eq!(format_token_count(999_500), "1M");
+        assert_eq!(format_token_count(1_234_567), "1.2M");
+        assert_eq!(format_token_count(2_000_000), "2M");
+    }
+
+    #[test]
+    fn context_window_urgency_bands() {
+        assert_eq!(context_window_urgency(0), ContextWindowUrgency::Critical);
+        assert_eq!(context_window_urgency(9), ContextWindowUrgency::Critical);
+        assert_eq!(context_window_urgency(10), ContextWindowUrgency::Warning);
+        assert_eq!(context_window_urgency(24), ContextWindowUrgency::Warning);
+        assert_eq!(context_window_urgency(25), ContextWindowUrgency::Normal);
+        assert_eq!(context_window_urgency(100), ContextWindowUrgency::Normal);
+    }
+
+    #[test]
+    fn narrow_footer_is_ellipsized() {
+        let props = FooterProps::builder().context_window_percent(72).build();
+        snapshot_footer_with_width("footer_shortcuts_ellipsized_width_30", 30, props.clone());
+        snapshot_footer_with_width(
+            "footer_ctrl_c_quit_ellipsized_width_20",
+            20,
+            FooterProps {
+                mode: FooterMode::CtrlCReminder { expires_at: None },
+                ..props.clone()
+            },
+        );
+        snapshot_footer_with_width(
+            "footer_ctrl_c_interrupt_width_30",
+            30,
+            FooterProps {
+                mode: FooterMode::CtrlCReminder { expires_at: None },
+                is_task_running: true,
+                ..props
+            },
+        );
+    }
+
+    #[test]
+    fn ellipsize_line_keeps_key_hints() {
+        let line = Line::from(vec![
+            "72%".into(),
+            " context left".into(),
+            " · ".into(),
+            "?".into(),
+            " for shortcuts".into(),
+        ]);
+        assert_eq!(
+            ellipsize_line(line.clone(), 12).to_string(),
+            "72% contex…?".to_string()
+        );
+        assert_eq!(
+            ellipsize_line(line.clone(), 28).to_string(),
+            "72% context left · ? for sh…".to_string()
+        );
+        assert_eq!(ellipsize_line(line.clone(), 40), line);
+        assert_eq!(ellipsize_line(line, 3).to_string(), "72…".to_string());
+    }
+
+    #[test]
+    fn notification_expires_at_deadline() {
+        let now = Instant::now();
+        assert!(!notification_expired(None, now));
+        assert!(!notification_expired(
+            Some(now + Duration::from_millis(1)),
+            now
+        ));
+        assert!(notification_expired(Some(now), now));
+    }
+
+    #[test]
+    fn ctrl_c_reminder_expires_after_timeout() {
+        let armed_at = Instant::now();
+        let timeout = CTRL_C_REMINDER_TIMEOUT;
+        let just_before = armed_at + timeout - Duration::from_millis(1);
+        let just_after = armed_at + timeout + Duration::from_millis(1);
+        assert!(!ctrl_c_reminder_expired(armed_at, armed_at, timeout));
+        assert!(!ctrl_c_reminder_expired(armed_at, just_before, timeout));
+        assert!(ctrl_c_reminder_expired(
+            armed_at,
+            armed_at + timeout,
+            timeout
+        ));
+        assert!(ctrl_c_reminder_expired(armed_at, just_after, timeout));
+    }
+
+    #[test]
+    fn countdown_ticks_on_whole_seconds_then_at_expiry() {
+        let now = Instant::now();
+        let expires_at = now + Duration::from_millis(2_500);
+        // "(3s)" turns into "(2s)" once two seconds remain.
+        assert_eq!(
+            next_countdown_tick(expires_at, now),
+            Some(now + Duration::from_millis(500))
+        );
+        let tick = now + Duration::from_millis(500);
+        assert_eq!(
+            next_countdown_tick(expires_at, tick),
+            Some(tick + Duration::from_secs(1))
+        );
+        let last_second = expires_at - Duration::from_millis(400);
+        assert_eq!(
+            next_countdown_tick(expires_at, last_second),
+            Some(expires_at)
+        );
+        assert_eq!(next_countdown_tick(expires_at, expires_at), None);
+    }
+
+    #[test]
+    fn idle_line_escalates_like_the_context_line() {
+        for percent in [
+            72,
+            CONTEXT_WARNING_PERCENT - 1,
+            CONTEXT_CRITICAL_PERCENT - 1,
+        ] {
+            let props = FooterProps::builder()
+                .context_window_percent(percent)
+                .build();
+            let idle = idle_line(&props);
+            let full = context_window_line(&props, Instant::now());
+            let percent_style = |line: &Line<'static>| {
+                line.spans
+                    .iter()
+                    .find(|span| span.content == format!("{percent}%"))
+                    .map(|span| span.style)
+            };
+            assert_eq!(percent_style(&idle), percent_style(&full), "{percent}%");
+        }
+        let critical = FooterProps::builder()
+            .context_window_percent(CONTEXT_CRITICAL_PERCENT - 1)
+            .build();
+        assert_eq!(
+            idle_line(&critical).spans[0].style,
+            Style::default().fg(Color::Red).bold()
+        );
+    }
+
+    #[test]
+    fn permissive_badges_get_distinct_styles() {
+        let style = |badge| sandbox_badge_span(badge).style;
+        let read_only = style(SandboxBadge::ReadOnly);
+        let auto = style(SandboxBadge::Auto);
+        let full_access = style(SandboxBadge::FullAccess);
+        assert_eq!(read_only, Style::default().dim());
+        assert_eq!(auto, Style::default().fg(Color::Cyan));
+        assert_eq!(full_access, Style::default().fg(Color::Red).bold());
+        assert_ne!(auto, read_only);
+        assert_ne!(full_access, read_only);
+        assert_ne!(full_access, auto);
+    }
+
+    #[test]
+    fn notification_styles_follow_severity() {
+        let style = |severity| notification_line("done", severity).style;
+        assert_eq!(style(NotificationSeverity::Info), Style::default().dim());
+        assert_eq!(style(NotificationSeverity::Warn), Style::default().bold());
+        assert_eq!(
+            style(NotificationSeverity::Error),
+            Style::default().fg(Color::Red).bold()
+        );
+    }
+
+    /// Shortcut state for a session with an earlier message to edit, on a
+    /// platform that can paste clipboard images.
+    fn session_shortcuts() -> ShortcutsState {
+        ShortcutsState {
+            clipboard_images_supported: true,
+            has_previous_message: true,
+            ..ShortcutsState::default()
+        }
+    }
+
+    /// One value of every `FooterMode` variant. The match has no wildcard, so
+    /// adding a variant fails to compile until it is listed here.
+    fn every_footer_mode() -> Vec<FooterMode> {
+        let modes = vec![
+            FooterMode::CtrlCReminder { expires_at: None },
+            FooterMode::Notification {
+                message: "careful".into(),
+                severity: NotificationSeverity::Warn,
+                expires_at: None,
+            },
+            FooterMode::Progress {
+                label: "Indexing".into(),
+                percent: 40,
+            },
+            FooterMode::ShortcutSummary,
+            FooterMode::CompactShortcuts(session_shortcuts()),
+            FooterMode::ShortcutOverlay(session_shortcuts()),
+            FooterMode::EscHint {
+                backtrack_armed: false,
+            },
+            FooterMode::ContextOnly,
+            FooterMode::Paused,
+            FooterMode::AwaitingApproval,
+            FooterMode::Idle,
+            FooterMode::Reconnecting(ReconnectState {
+                attempt: 1,
+                max_attempts: 5,
+                next_retry_at: None,
+            }),
+            FooterMode::Empty { keep_row: true },
+            FooterMode::Empty { keep_row: false },
+        ];
+        for mode in &modes {
+            match mode {
+                FooterMode::CtrlCReminder { .. }
+                | FooterMode::Notification { .. }
+                | FooterMode::Progress { .. }
+                | FooterMode::ShortcutSummary
+                | FooterMode::CompactShortcuts(_)
+                | FooterMode::ShortcutOverlay(_)
+                | FooterMode::EscHint { .. }
+                | FooterMode::ContextOnly
+                | FooterMode::Paused
+                | FooterMode::AwaitingApproval
+                | FooterMode::Idle
+                | FooterMode::Reconnecting(_)
+                | FooterMode::Empty { .. } => {}
+            }
+        }
+        modes
+    }
+
+    #[test]
+    fn only_collapsed_footer_takes_no_rows() {
+        for mode in every_footer_mode() {
+            let props = FooterProps::builder().mode(mode.clone()).build();
+            for width in [0, 20, 80] {
+                let rows = footer_height(&props, width);
+                if mode == (FooterMode::Empty { keep_row: false }) {
+                    assert_eq!(rows, 0, "{mode:?} at width {width}");
+                } else {
+                    assert!(rows >= 1, "{mode:?} at width {width}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn toggle_shortcut_mode_covers_every_mode() {
+        let shortcuts = ShortcutsState {
+            use_shift_enter_hint: true,
+            ..session_shortcuts()
+        };
+        for current in every_footer_mode() {
+            for ctrl_c_hint in [false, true] {
+                let expected = match &current {
+                    FooterMode::CtrlCReminder { .. } if ctrl_c_hint => current.clone(),
+                    FooterMode::Notification { .. } | FooterMode::Paused => current.clone(),
+                    FooterMode::CtrlCReminder { .. } | FooterMode::ShortcutOverlay(_) => {
+                        FooterMode::ShortcutSummary
+                    }
+                    FooterMode::ShortcutSummary | FooterMode::Idle => {
+                        FooterMode::CompactShortcuts(shortcuts)
+                    }
+                    FooterMode::CompactShortcuts(_)
+                    | FooterMode::AwaitingApproval
+                    | FooterMode::Reconnecting(_)
+                    | FooterMode::Progress { .. }
+                    | FooterMode::EscHint { .. }
+                    | FooterMode::ContextOnly
+                    | FooterMode::Empty { .. } => FooterMode::ShortcutOverlay(shortcuts),
+                };
+                assert_eq!(
+                    toggle_shortcut_mode(current.clone(), ctrl_c_hint, shortcuts, 1),
+                    expected,
+                    "toggle from {current:?} with ctrl_c_hint={ctrl_c_hint}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compact_shortcut_follows_context_window() {
+        let overlay_text = |percent: Option<u8>| {
+            let props = FooterProps {
+                mode: FooterMode::ShortcutOverlay(session_shortcuts()),
+                context_window_percent: percent,
+                ..FooterProps::builder().build()
+            };
+            footer_lines(&props, 120, Instant::now())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        for (percent, shown) in [
+            (None, false),
+            (Some(100), false),
+            (Some(CONTEXT_WARNING_PERCENT + 1), false),
+            (Some(CONTEXT_WARNING_PERCENT), true),
+            (Some(5), true),
+            (Some(0), true),
+        ] {
+            assert_eq!(
+                overlay_text(percent).contains("/compact to free context"),
+                shown,
+                "context_window_percent={percent:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn narrow_overlay_never_exceeds_width() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                esc_backtrack_hint: true,
+                ..session_shortcuts()
+            }))
+            .context_window_percent(5)
+            .build();
+        for width in [20, 24, 30, 36] {
+            let FooterMode::ShortcutOverlay(state) = props.mode else {
+                unreachable!();
+            };
+            // Entries wrap rather than being cut off.
+            let lines: Vec<Line<'static>> = overlay_content(&props, state, width)
+                .into_iter()
+                .map(|row| row.line)
+                .collect();
+            let available = usize::from(width) - FOOTER_INDENT_COLS;
+            assert!(
+                lines.iter().all(|line| rendered_width(line) <= available),
+                "overlay overflows at width {width}: {lines:?}"
+            );
+            for page in 0..overlay_page_count(&props, width) {
+                let page_props = FooterProps {
+                    mode: FooterMode::ShortcutOverlay(ShortcutsState { page, ..state }),
+                    ..props.clone()
+                };
+                let rows = footer_layout(&page_props, width, Instant::now());
+                assert!(
+                    rows.iter()
+                        .all(|row| rendered_width(row) <= usize::from(width)),
+                    "page {page} overflows at width {width}: {rows:?}"
+                );
+            }
+            let text = lines
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ");
+            let words = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            assert!(
+                words.contains("esc again to edit previous message"),
+                "{words}"
+            );
+        }
+    }
+
+    #[test]
+    fn indent_cols_moves_footer_content() {
+        for (indent, expected_col) in [(None, FOOTER_INDENT_COLS), (Some(0), 0), (Some(6), 6)] {
+            let mut builder =
+                FooterProps::builder().mode(FooterMode::ShortcutOverlay(session_shortcuts()));
+            if let Some(cols) = indent {
+                builder = builder.indent_cols(cols);
+            }
+            let props = builder.build();
+            let width = 60;
+            let area = Rect::new(0, 0, width, footer_height(&props, width));
+            let mut buf = Buffer::empty(area);
+            render_footer(area, &mut buf, &props, Instant::now());
+            for y in 0..area.height {
+                let first = (0..area.width)
+                    .find(|&x| buf[(x, y)].symbol() != " ")
+                    .map(usize::from);
+                assert_eq!(first, Some(expected_col), "indent {indent:?}, row {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn builder_flags_inconsistent_props() {
+        let props = FooterProps::builder()
+            .context_window_percent(40)
+            .context_tokens(10, 100)
+            .tokens_per_second(12.5)
+            .build();
+        assert_eq!(props.inconsistency(), None);
+
+        let cases = [
+            FooterProps {
+                context_window_percent: Some(101),
+                ..props.clone()
+            },
+            FooterProps {
+                context_tokens_max: None,
+                ..props.clone()
+            },
+            FooterProps {
+                context_tokens_max: Some(0),
+                ..props.clone()
+            },
+            FooterProps {
+                tokens_per_second: Some(f32::NAN),
+                ..props
+            },
+        ];
+        for case in cases {
+            assert!(case.inconsistency().is_some(), "{case:?}");
+        }
+    }
+
+    #[test]
+    fn active_profile_snapshots() {
+        let overlay = FooterProps::builder().mode(FooterMode::ShortcutOverlay(session_shortcuts()));
+        snapshot_footer(
+            "footer_shortcuts_with_profile",
+            overlay.clone().active_profile("work").build(),
+        );
+        snapshot_footer("footer_shortcuts_without_profile", overlay.build());
+    }
 
-    fn snapshot_footer(name: &str, props: FooterProps) {
-        let height = footer_height(props).max(1);
-        let mut terminal = Terminal::new(TestBackend::new(80, height)).unwrap();
-        terminal
-            .draw(|f| {
-                let area = Rect::new(0, 0, f.area().width, height);
-                render_footer(area, f.buffer_mut(), props);
-            })
-            .unwrap();
-        assert_snapshot!(name, terminal.backend());
+    #[test]
+    fn clear_context_shortcut_snapshots() {
+        for percent in [14, 16] {
+            snapshot_footer(
+                &format!("footer_shortcuts_clear_context_{percent}"),
+                FooterProps::builder()
+                    .mode(FooterMode::ShortcutOverlay(session_shortcuts()))
+                    .context_window_percent(percent)
+                    .build(),
+            );
+        }
     }
 
     #[test]
-    fn footer_snapshots() {
+    fn overlay_with_low_context_snapshots() {
+        for width in [40, 80] {
+            snapshot_footer_with_width(
+                &format!("footer_shortcuts_context_low_width_{width}"),
+                width,
+                FooterProps::builder()
+                    .mode(FooterMode::ShortcutOverlay(session_shortcuts()))
+                    .context_window_percent(8)
+                    .build(),
+            );
+        }
+    }
+
+    #[test]
+    fn footer_style_maps_idle_modes() {
+        let cases = [
+            (FooterStyle::Full, FooterMode::Idle, FooterMode::Idle, 1),
+            (
+                FooterStyle::Minimal,
+                FooterMode::Idle,
+                FooterMode::Empty { keep_row: true },
+                1,
+            ),
+            (
+                FooterStyle::Hidden,
+                FooterMode::Idle,
+                FooterMode::Empty { keep_row: false },
+                0,
+            ),
+            (
+                FooterStyle::Full,
+                FooterMode::ShortcutSummary,
+                FooterMode::ShortcutSummary,
+                1,
+            ),
+            (
+                FooterStyle::Full,
+                FooterMode::ContextOnly,
+                FooterMode::ContextOnly,
+                1,
+            ),
+            (
+                FooterStyle::Minimal,
+                FooterMode::ShortcutSummary,
+                FooterMode::Empty { keep_row: true },
+                1,
+            ),
+            (
+                FooterStyle::Minimal,
+                FooterMode::ContextOnly,
+                FooterMode::ContextOnly,
+                1,
+            ),
+            (
+                FooterStyle::Minimal,
+                FooterMode::CtrlCReminder { expires_at: None },
+                FooterMode::CtrlCReminder { expires_at: None },
+                1,
+            ),
+            (
+                FooterStyle::Minimal,
+                FooterMode::EscHint {
+                    backtrack_armed: false,
+                },
+                FooterMode::EscHint {
+                    backtrack_armed: false,
+                },
+                1,
+            ),
+            (
+                FooterStyle::Hidden,
+                FooterMode::ShortcutSummary,
+                FooterMode::Empty { keep_row: false },
+                0,
+            ),
+            (
+                FooterStyle::Hidden,
+                FooterMode::ContextOnly,
+                FooterMode::Empty { keep_row: false },
+                0,
+            ),
+            (
+                FooterStyle::Hidden,
+                FooterMode::CtrlCReminder { expires_at: None },
+                FooterMode::CtrlCReminder { expires_at: None },
+                1,
+            ),
+            (
+                FooterStyle::Hidden,
+                FooterMode::EscHint {
+                    backtrack_armed: false,
+                },
+                FooterMode::EscHint {
+                    backtrack_armed: false,
+                },
+                1,
+            ),
+        ];
+        for (style, mode, expected, height) in cases {
+            let mapped = apply_footer_style(mode.clone(), style);
+            assert_eq!(mapped, expected, "{style:?} {mode:?}");
+            let props = FooterProps::builder().mode(mapped).build();
+            assert_eq!(footer_height(&props, 80), height, "{style:?} {mode:?}");
+        }
+
+        // Whatever the style, the overlay keeps its rows.
+        for style in [FooterStyle::Full, FooterStyle::Minimal, FooterStyle::Hidden] {
+            assert_eq!(
+                apply_footer_style(FooterMode::ShortcutOverlay(session_shortcuts()), style),
+                FooterMode::ShortcutOverlay(session_shortcuts())
+            );
+        }
+    }
+
+    #[test]
+    fn build_columns_emits_one_line_per_row() {
+        // Exhaustive over small configs in place of a property test.
+        for columns in 1..=5 {
+            for count in 0..=12 {
+                for (column_padding, column_gap) in
+                    [(vec![], 0), (vec![4, 4], 4), (vec![0, 9, 1, 2], 1)]
+                {
+                    let entries: Vec<Line<'static>> = (0..count)
+                        .map(|idx| Line::from("x".repeat(idx % 7)))
+                        .collect();
+                    let config = ColumnConfig {
+                        columns,
+                        column_padding,
+                        column_gap,
+                    };
+                    for available in [0, 40, 200] {
+                        assert_eq!(
+                            build_columns(entries.clone(), &config, available).len(),
+                            count.div_ceil(columns),
+                            "{config:?} with {count} entries in {available} columns"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn build_columns_aligns_wide_character_labels() {
+        let entries = vec![
+            Line::from(vec!["/".into(), " 查看命令".into()]),
+            Line::from("R first"),
+            Line::from(vec![Span::from("ctrl + j").bold(), " 换行".into()]),
+            Line::from("R second"),
+            Line::from("👩‍💻 custom"),
+            Line::from("R third"),
+            Line::from("plain"),
+            Line::from("R fourth"),
+        ];
+        let config = ColumnConfig {
+            columns: 2,
+            ..ColumnConfig::default()
+        };
+        let lines = build_columns(entries, &config, 80);
+        let area = Rect::new(0, 0, 80, lines.len() as u16);
+        let mut buf = Buffer::empty(area);
+        Paragraph::new(lines).render(area, &mut buf);
+
+        // The second column starts in the same cell on every row.
+        let starts: Vec<Option<u16>> = (0..area.height)
+            .map(|y| (0..area.width).find(|&x| buf[(x, y)].symbol() == "R"))
+            .collect();
+        let widest = ["/ 查看命令", "ctrl + j 换行", "👩‍💻 custom", "plain"]
+            .iter()
+            .map(|label| label.width())
+            .max()
+            .unwrap_or_default();
+        let expected = u16::try_from(widest + 4 + config.column_gap).ok();
+        assert_eq!(starts, vec![expected; 4]);
+    }
+
+    #[test]
+    fn rendered_width_counts_wide_graphemes() {
+        assert_eq!(rendered_width(&Line::from("查看")), 4);
+        assert_eq!(
+            rendered_width(&Line::from(vec!["a".into(), "界".into()])),
+            3
+        );
+        assert_eq!(rendered_width(&Line::from("👩‍💻")), 2);
+    }
+
+    #[test]
+    fn right_aligned_context_snapshots() {
+        let props = FooterProps::builder()
+            .context_window_percent(72)
+            .context_tokens(93_000, 128_000)
+            .build();
+        // Room for the hint on the left and the context on the right.
+        snapshot_footer_with_width("footer_context_right_aligned_width_80", 80, props.clone());
+        // Too narrow for both with a gap: context first, then the hint.
+        snapshot_footer_with_width("footer_context_inline_width_45", 45, props);
+    }
+
+    #[test]
+    fn vim_keymap_overlay_snapshot() {
         snapshot_footer(
-            "footer_shortcuts_default",
-            FooterProps {
-                mode: FooterMode::ShortcutSummary,
-                esc_backtrack_hint: false,
-                use_shift_enter_hint: false,
-                is_task_running: false,
-                context_window_percent: None,
-            },
+            "footer_shortcuts_vim_keymap",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    keymap: KeymapStyle::Vim,
+                    ..session_shortcuts()
+                }))
+                .build(),
         );
+    }
+
+    #[test]
+    fn shortcut_binding_matches_session_conditions() {
+        let binding = |condition| ShortcutBinding {
+            key: KeyChord::single(key_hint::plain(KeyCode::Esc)),
+            condition,
+        };
+        let idle = ShortcutsState::default();
+        let cases = [
+            (
+                DisplayCondition::WhenTaskRunning,
+                ShortcutsState {
+                    is_task_running: true,
+                    ..idle
+                },
+            ),
+            (
+                DisplayCondition::WhenClipboardImages,
+                ShortcutsState {
+                    clipboard_images_supported: true,
+                    ..idle
+                },
+            ),
+            (
+                DisplayCondition::WhenPreviousMessage,
+                ShortcutsState {
+                    has_previous_message: true,
+                    ..idle
+                },
+            ),
+        ];
+        let props = FooterProps::builder().build();
+        for (condition, enabled) in cases {
+            assert!(binding(condition).matches(&props, enabled), "{condition:?}");
+            assert!(!binding(condition).matches(&props, idle), "{condition:?}");
+        }
+    }
+
+    #[test]
+    fn display_condition_combinators() {
+        use DisplayCondition::*;
+        const RUNNING: DisplayCondition = WhenTaskRunning;
+        const PREVIOUS: DisplayCondition = WhenPreviousMessage;
+        const BOTH: DisplayCondition = All(&[RUNNING, PREVIOUS]);
+        const EITHER: DisplayCondition = Any(&[RUNNING, PREVIOUS]);
+        const NOT_RUNNING: DisplayCondition = Not(&RUNNING);
+        const NEITHER: DisplayCondition = Not(&EITHER);
+
+        let props = FooterProps::builder().build();
+        assert!(All(&[]).matches(&props, ShortcutsState::default()));
+        assert!(!Any(&[]).matches(&props, ShortcutsState::default()));
+        for is_task_running in [false, true] {
+            for has_previous_message in [false, true] {
+                let state = ShortcutsState {
+                    is_task_running,
+                    has_previous_message,
+                    ..ShortcutsState::default()
+                };
+                let label = format!("running={is_task_running} previous={has_previous_message}");
+                assert_eq!(
+                    BOTH.matches(&props, state),
+                    is_task_running && has_previous_message,
+                    "{label}"
+                );
+                assert_eq!(
+                    EITHER.matches(&props, state),
+                    is_task_running || has_previous_message,
+                    "{label}"
+                );
+                assert_eq!(
+                    NOT_RUNNING.matches(&props, state),
+                    !is_task_running,
+                    "{label}"
+                );
+                assert_eq!(
+                    NEITHER.matches(&props, state),
+                    !is_task_running && !has_previous_message,
+                    "{label}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edit_previous_hidden_while_task_runs() {
+        let overlay_text = |is_task_running| {
+            let props = FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    is_task_running,
+                    ..session_shortcuts()
+                }))
+                .build();
+            footer_lines(&props, 120, Instant::now())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        assert!(overlay_text(false).contains("to edit previous message"));
+        assert!(!overlay_text(true).contains("to edit previous message"));
+    }
 
+    #[test]
+    fn running_and_idle_overlay_snapshots() {
+        let idle = session_shortcuts();
         snapshot_footer(
-            "footer_shortcuts_shift_and_esc",
-            FooterProps {
-                mode: FooterMode::ShortcutOverlay,
-                esc_backtrack_hint: true,
-                use_shift_enter_hint: true,
-                is_task_running: false,
-                context_window_percent: None,
-            },
+            "footer_shortcuts_overlay_idle",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(idle))
+                .build(),
+        );
+        snapshot_footer(
+            "footer_shortcuts_overlay_running",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    is_task_running: true,
+                    esc_interrupts: true,
+                    ..idle
+                }))
+                .task_running(true)
+                .build(),
+        );
+        // With the status indicator hidden, esc goes to the composer and only
+        // ctrl + c interrupts.
+        snapshot_footer(
+            "footer_shortcuts_overlay_running_status_hidden",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    is_task_running: true,
+                    ..idle
+                }))
+                .task_running(true)
+                .build(),
         );
+    }
 
+    #[test]
+    fn interrupt_entry_only_while_task_runs() {
+        let overlay_text = |state: ShortcutsState| {
+            let props = FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(state))
+                .task_running(state.is_task_running)
+                .build();
+            footer_lines(&props, 120, Instant::now())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        // A stale esc_interrupts flag must not leak into the idle overlay.
+        let idle = overlay_text(ShortcutsState {
+            esc_interrupts: true,
+            ..session_shortcuts()
+        });
+        assert_eq!(idle, overlay_text(session_shortcuts()));
+        assert!(!idle.contains("to interrupt"), "{idle}");
+        assert!(idle.contains("ctrl + c to exit"), "{idle}");
+
+        let running = overlay_text(ShortcutsState {
+            is_task_running: true,
+            ..session_shortcuts()
+        });
+        assert!(running.contains("ctrl + c to interrupt"), "{running}");
+        assert!(!running.contains("to exit"), "{running}");
+    }
+
+    #[test]
+    fn hidden_entries_leave_no_gaps_snapshot() {
         snapshot_footer(
-            "footer_ctrl_c_quit_idle",
-            FooterProps {
-                mode: FooterMode::CtrlCReminder,
-                esc_backtrack_hint: false,
-                use_shift_enter_hint: false,
-                is_task_running: false,
-                context_window_percent: None,
-            },
+            "footer_shortcuts_overlay_fresh_session",
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState::default()))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn highlighted_keys_snapshots() {
+        // Brackets mark the text drawn at full brightness.
+        let marked_overlay = |highlight_keys| {
+            let props = FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    highlight_keys,
+                    ..session_shortcuts()
+                }))
+                .build();
+            footer_layout(&props, 80, Instant::now())
+                .iter()
+                .map(|line| {
+                    line.spans
+                        .iter()
+                        .map(|span| {
+                            let style = line.style.patch(span.style);
+                            if style.add_modifier.contains(Modifier::DIM)
+                                || span.content.trim().is_empty()
+                            {
+                                span.content.to_string()
+                            } else {
+                                format!("[{}]", span.content)
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        assert_snapshot!("footer_shortcuts_keys_highlighted", marked_overlay(true));
+        assert_snapshot!("footer_shortcuts_keys_uniform", marked_overlay(false));
+    }
+
+    #[test]
+    fn toggle_steps_through_overlay_pages() {
+        let shortcuts = session_shortcuts();
+        let on_page = |page| FooterMode::ShortcutOverlay(ShortcutsState { page, ..shortcuts });
+        assert_eq!(
+            toggle_shortcut_mode(on_page(0), false, shortcuts, 3),
+            on_page(1)
+        );
+        assert_eq!(
+            toggle_shortcut_mode(on_page(1), false, shortcuts, 3),
+            on_page(2)
+        );
+        assert_eq!(
+            toggle_shortcut_mode(on_page(2), false, shortcuts, 3),
+            FooterMode::ShortcutSummary
+        );
+        // An overlay that fits, or one left past its end by a resize, closes.
+        assert_eq!(
+            toggle_shortcut_mode(on_page(0), false, shortcuts, 1),
+            FooterMode::ShortcutSummary
+        );
+        assert_eq!(
+            toggle_shortcut_mode(on_page(4), false, shortcuts, 3),
+            FooterMode::ShortcutSummary
+        );
+        // Opening the overlay always starts on the first page.
+        assert_eq!(
+            toggle_shortcut_mode(FooterMode::CompactShortcuts(shortcuts), false, shortcuts, 3),
+            on_page(0)
+        );
+    }
+
+    #[test]
+    fn render_footer_to_string_returns_plain_rows() {
+        let rows = render_footer_to_string(
+            40,
+            FooterProps::builder().context_window_percent(72).build(),
+        );
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].contains("for shortcuts"), "{rows:?}");
+        assert!(rows[0].ends_with("72% context left"), "{rows:?}");
+
+        let rows = render_footer_to_string(
+            80,
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(session_shortcuts()))
+                .build(),
+        );
+        assert_eq!(rows.first().map(String::as_str), Some("  Composer"));
+    }
+
+    #[test]
+    fn overlay_selection_wraps_across_columns() {
+        use ShortcutId::*;
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(session_shortcuts()))
+            .build();
+        let nav = OverlayNavigation::new(&props, 80);
+        // Two columns of composer entries, then the session row.
+        assert_eq!(
+            nav.ids().collect::<Vec<_>>(),
+            vec![
+                Commands,
+                InsertNewline,
+                FilePaths,
+                PasteImage,
+                EditPrevious,
+                Quit,
+                ShowTranscript,
+            ]
+        );
+        let step = |from, direction| nav.step(Some(from), direction);
+
+        // Left and right carry over to the neighbouring row, and around.
+        assert_eq!(step(InsertNewline, OverlayMove::Right), Some(FilePaths));
+        assert_eq!(step(FilePaths, OverlayMove::Left), Some(InsertNewline));
+        assert_eq!(step(ShowTranscript, OverlayMove::Right), Some(Commands));
+        assert_eq!(step(Commands, OverlayMove::Left), Some(ShowTranscript));
+
+        // Up and down keep the column, falling back to the row's last entry
+        // left of it, and wrap between the first and last rows.
+        assert_eq!(step(InsertNewline, OverlayMove::Down), Some(PasteImage));
+        assert_eq!(step(PasteImage, OverlayMove::Down), Some(EditPrevious));
+        assert_eq!(step(EditPrevious, OverlayMove::Down), Some(Quit));
+        assert_eq!(step(ShowTranscript, OverlayMove::Down), Some(InsertNewline));
+        assert_eq!(step(Commands, OverlayMove::Up), Some(Quit));
+
+        // The first move picks an end of the list.
+        assert_eq!(nav.step(None, OverlayMove::Right), Some(Commands));
+        assert_eq!(nav.step(None, OverlayMove::Up), Some(ShowTranscript));
+        assert_eq!(
+            OverlayNavigation::default().step(None, OverlayMove::Down),
+            None
+        );
+    }
+
+    #[test]
+    fn selected_entry_is_reversed_on_its_page() {
+        let state = ShortcutsState {
+            selected: Some(ShortcutId::ShowTranscript),
+            ..session_shortcuts()
+        };
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(state))
+            .build();
+        // At this width the session group lands on the second page.
+        assert_eq!(overlay_selection_page(&props, 40), Some(1));
+
+        let reversed_on = |page| {
+            overlay_page(overlay_content(&props, state, 40), page)
+                .iter()
+                .flat_map(|line| line.spans.iter())
+                .filter(|span| span.style.add_modifier.contains(Modifier::REVERSED))
+                .map(|span| span.content.to_string())
+                .collect::<String>()
+        };
+        assert_eq!(reversed_on(0), "");
+        assert_eq!(reversed_on(1), "ctrl + t to view transcript");
+    }
+
+    #[test]
+    fn paginated_overlay_snapshots() {
+        let width = 40;
+        let page_props = |page| {
+            FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    page,
+                    ..session_shortcuts()
+                }))
+                .build()
+        };
+        assert_eq!(overlay_page_count(&page_props(0), width), 2);
+        assert_eq!(overlay_page_count(&page_props(0), 80), 1);
+        for page in 0..2 {
+            let props = page_props(page);
+            assert!(usize::from(footer_height(&props, width)) <= OVERLAY_MAX_ROWS);
+            snapshot_footer_with_width(
+                &format!("footer_shortcuts_overlay_page_{}", page + 1),
+                width,
+                props,
+            );
+        }
+        // A page past the end shows the last one.
+        assert_eq!(
+            footer_lines(&page_props(5), width, Instant::now()),
+            footer_lines(&page_props(1), width, Instant::now())
         );
+    }
 
+    #[test]
+    fn compact_shortcuts_snapshot() {
         snapshot_footer(
-            "footer_ctrl_c_quit_running",
-            FooterProps {
-                mode: FooterMode::CtrlCReminder,
-                esc_backtrack_hint: false,
-                use_shift_enter_hint: false,
-                is_task_running: true,
-                context_window_percent: None,
-            },
+            "footer_compact_shortcuts",
+            FooterProps::builder()
+                .mode(FooterMode::CompactShortcuts(session_shortcuts()))
+                .context_window_percent(72)
+                .build(),
         );
+    }
+
+    #[test]
+    fn ascii_key_hints_overlay_snapshots() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                use_shift_enter_hint: true,
+                ..session_shortcuts()
+            }))
+            .build();
+        key_hint::with_ascii_key_hints(false, || {
+            snapshot_footer("footer_shortcuts_overlay_unicode_key_hints", props.clone());
+        });
+        key_hint::with_ascii_key_hints(true, || {
+            snapshot_footer("footer_shortcuts_overlay_ascii_key_hints", props);
+        });
+    }
+
+    #[test]
+    fn modifier_style_overlay_snapshots() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                use_shift_enter_hint: true,
+                ..session_shortcuts()
+            }))
+            .build();
+        for (name, style) in [
+            ("textual", KeyHintModifiers::Textual),
+            ("symbolic", KeyHintModifiers::Symbolic),
+        ] {
+            key_hint::with_modifier_style(style, || {
+                snapshot_footer(
+                    &format!("footer_shortcuts_overlay_{name}_modifiers"),
+                    props.clone(),
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn remapped_transcript_key_snapshot() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                table: ShortcutTable::default().remap(
+                    ShortcutId::ShowTranscript,
+                    key_hint::ctrl(KeyCode::Char('o')),
+                ),
+                ..session_shortcuts()
+            }))
+            .build();
+        snapshot_footer("footer_shortcuts_remapped_transcript", props);
+    }
+
+    #[test]
+    fn remapped_key_keeps_display_condition() {
+        let newline = key_hint::alt(KeyCode::Enter);
+        let overlay_text = |use_shift_enter_hint| {
+            let props = FooterProps::builder()
+                .mode(FooterMode::ShortcutOverlay(ShortcutsState {
+                    use_shift_enter_hint,
+                    table: ShortcutTable::default().remap(ShortcutId::InsertNewline, newline),
+                    ..session_shortcuts()
+                }))
+                .build();
+            footer_lines(&props, 120, Instant::now())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        // Either default binding's condition still picks the entry; the key
+        // shown is the rebound one.
+        for use_shift_enter_hint in [false, true] {
+            let text = overlay_text(use_shift_enter_hint);
+            assert!(text.contains("alt + enter for newline"), "{text}");
+            assert!(!text.contains("ctrl + j"), "{text}");
+            assert!(!text.contains("shift + enter"), "{text}");
+        }
+    }
 
+    #[test]
+    fn paused_snapshot() {
         snapshot_footer(
-            "footer_esc_hint_idle",
-            FooterProps {
-                mode: FooterMode::EscHint,
-                esc_backtrack_hint: false,
-                use_shift_enter_hint: false,
-                is_task_running: false,
-                context_window_percent: None,
-            },
+            "footer_paused",
+            FooterProps::builder()
+                .mode(FooterMode::Paused)
+                .task_running(true)
+                .build(),
         );
+    }
 
+    #[test]
+    fn awaiting_approval_snapshot() {
         snapshot_footer(
-            "footer_esc_hint_primed",
-            FooterProps {
-                mode: FooterMode::EscHint,
-                esc_backtrack_hint: true,
-                use_shift_enter_hint: false,
-                is_task_running: false,
-                context_window_percent: None,
-            },
+            "footer_awaiting_approval",
+            FooterProps::builder()
+                .mode(FooterMode::AwaitingApproval)
+                .task_running(true)
+                .build(),
         );
+    }
 
+    #[test]
+    fn idle_snapshots() {
+        let props = FooterProps::builder()
+            .mode(FooterMode::Idle)
+            .sandbox_badge(SandboxBadge::Auto)
+            .context_window_percent(72)
+            .context_tokens(36_000, 128_000)
+            .model("gpt-5-codex")
+            .build();
+        snapshot_footer("footer_idle", props.clone());
         snapshot_footer(
-            "footer_shortcuts_context_running",
+            "footer_idle_context_low",
             FooterProps {
-                mode: FooterMode::ShortcutSummary,
-                esc_backtrack_hint: false,
-                use_shift_enter_hint: false,
-                is_task_running: true,
-                context_window_percent: Some(72),
+                context_window_percent: Some(8),
+                ..props
             },
         );
     }
+
+    #[test]
+    fn footer_cache_reuses_lines_for_identical_props() {
+        let cache = FooterLineCache::default();
+        let props = FooterProps::builder()
+            .context_window_percent(72)
+            .model("gpt-5-codex")
+            .build();
+        let area = Rect::new(0, 0, 80, cache.height(&props, 80));
+        assert_eq!(area.height, 1);
+
+        // Swap the cached lines for a sentinel: only a cache hit can draw it.
+        if let Some(entry) = cache.entry.borrow_mut().as_mut() {
+            entry.lines = vec![Line::from("cached")];
+        }
+        let mut buf = Buffer::empty(area);
+        cache.render(area, &mut buf, &props, Instant::now());
+        let row: String = (0..6).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(row, "cached");
+
+        // Different props or width lay the footer out again.
+        let mut buf = Buffer::empty(area);
+        cache.render(
+            area,
+            &mut buf,
+            &FooterProps::builder().build(),
+            Instant::now(),
+        );
+        let row: String = (0..6).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_ne!(row, "cached");
+    }
+
+    #[test]
+    fn footer_cache_rebuilds_only_clock_spans() {
+        let cache = FooterLineCache::default();
+        let started_at = Instant::now();
+        let props = FooterProps::builder()
+            .context_window_percent(72)
+            .task_running(true)
+            .task_started_at(started_at)
+            .build();
+        let area = Rect::new(0, 0, 80, 1);
+        let render = |elapsed| {
+            let mut buf = Buffer::empty(area);
+            cache.render(area, &mut buf, &props, started_at + elapsed);
+            (0..area.width)
+                .map(|x| buf[(x, 0)].symbol())
+                .collect::<String>()
+        };
+
+        let row = render(Duration::from_millis(4_200));
+        assert!(row.contains("4.2s"), "{row}");
+
+        // Swap the indent for a sentinel: only a cache hit can draw it.
+        if let Some(entry) = cache.entry.borrow_mut().as_mut() {
+            entry.lines[0].spans[0] = Span::from("##");
+        }
+        let row = render(Duration::from_millis(4_300));
+        assert!(row.starts_with("##"), "{row}");
+        assert!(row.contains("4.3s"), "{row}");
+
+        // A wider elapsed time moves the right-aligned context, so the line
+        // is laid out again.
+        let row = render(Duration::from_secs(10));
+        assert!(!row.starts_with("##"), "{row}");
+        assert!(row.contains("10.0s"), "{row}");
+    }
+
+    #[test]
+    fn footer_cache_lays_out_again_once_a_deadline_passes() {
+        let cache = FooterLineCache::default();
+        let now = Instant::now();
+        let props = FooterProps::builder()
+            .mode(FooterMode::CtrlCReminder {
+                expires_at: Some(now + Duration::from_secs(3)),
+            })
+            .build();
+        let area = Rect::new(0, 0, 80, 1);
+        let render = |at| {
+            let mut buf = Buffer::empty(area);
+            cache.render(area, &mut buf, &props, at);
+            (0..area.width)
+                .map(|x| buf[(x, 0)].symbol())
+                .collect::<String>()
+        };
+
+        assert!(render(now).contains("again to quit (3s)"));
+        assert!(render(now + Duration::from_millis(2_500)).contains("again to quit (1s)"));
+        let row = render(now + Duration::from_secs(3));
+        assert!(row.contains("for shortcuts"), "{row}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn footer_modes_serialize_to_tagged_json() {
+        use serde_json::json;
+
+        let expected = [
+            json!({ "kind": "ctrl_c_reminder", "expires_at": null }),
+            json!({
+                "kind": "notification",
+                "message": "careful",
+                "severity": "warn",
+                "expires_at": null,
+            }),
+            json!({ "kind": "progress", "label": "Indexing", "percent": 40 }),
+            json!({ "kind": "shortcut_summary" }),
+            json!({
+                "kind": "compact_shortcuts",
+                "use_shift_enter_hint": false,
+                "esc_backtrack_hint": false,
+                "keymap": "emacs",
+                "is_task_running": false,
+                "esc_interrupts": false,
+                "clipboard_images_supported": true,
+                "has_previous_message": true,
+                "highlight_keys": false,
+                "page": 0,
+            }),
+            json!({
+                "kind": "shortcut_overlay",
+                "use_shift_enter_hint": false,
+                "esc_backtrack_hint": false,
+                "keymap": "emacs",
+                "is_task_running": false,
+                "esc_interrupts": false,
+                "clipboard_images_supported": true,
+                "has_previous_message": true,
+                "highlight_keys": false,
+                "page": 0,
+            }),
+            json!({ "kind": "esc_hint", "backtrack_armed": false }),
+            json!({ "kind": "context_only" }),
+            json!({ "kind": "paused" }),
+            json!({ "kind": "awaiting_approval" }),
+            json!({ "kind": "idle" }),
+            json!({
+                "kind": "reconnecting",
+                "attempt": 1,
+                "max_attempts": 5,
+                "next_retry_at": null,
+            }),
+            json!({ "kind": "empty", "keep_row": true }),
+            json!({ "kind": "empty", "keep_row": false }),
+        ];
+        let modes = every_footer_mode();
+        assert_eq!(modes.len(), expected.len());
+        for (mode, expected) in modes.iter().zip(expected) {
+            let text = serde_json::to_string(mode).expect("serialize mode");
+            let parsed: serde_json::Value = serde_json::from_str(&text).expect("parse mode");
+            assert_eq!(parsed, expected, "{mode:?}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn footer_modes_round_trip_through_json() {
+        for mode in every_footer_mode() {
+            let text = serde_json::to_string(&mode).expect("serialize mode");
+            let parsed: FooterMode = serde_json::from_str(&text).expect("deserialize mode");
+            assert_eq!(parsed, mode);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn footer_props_serialize_instants_as_unix_millis() {
+        use serde_json::json;
+        use std::time::SystemTime;
+        use std::time::UNIX_EPOCH;
+
+        let now_ms = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_millis() as u64)
+        };
+        let before = now_ms();
+        let props = FooterProps::builder()
+            .mode(FooterMode::CtrlCReminder {
+                expires_at: Some(Instant::now() + Duration::from_secs(60)),
+            })
+            .task_running(true)
+            .task_started_at(Instant::now() - Duration::from_secs(60))
+            .sandbox_badge(SandboxBadge::FullAccess)
+            .model("gpt-5-codex")
+            .reasoning_effort(ReasoningEffortConfig::Medium)
+            .build();
+        let mut value = serde_json::to_value(&props).expect("serialize props");
+        let after = now_ms();
+
+        let take_ms = |value: &mut serde_json::Value| {
+            value.take().as_u64().expect("instant serialized as u64")
+        };
+        let started = take_ms(&mut value["task_started_at"]);
+        assert!((before - 60_000..=after - 60_000).contains(&started));
+        let expires = take_ms(&mut value["mode"]["expires_at"]);
+        assert!((before + 60_000..=after + 60_000).contains(&expires));
+
+        assert_eq!(
+            value,
+            json!({
+                "mode": { "kind": "ctrl_c_reminder", "expires_at": null },
+                "is_task_running": true,
+                "sandbox_badge": "full_access",
+                "context_window_percent": null,
+                "context_tokens_used": null,
+                "context_tokens_max": null,
+                "tokens_per_second": null,
+                "model": "gpt-5-codex",
+                "reasoning_effort": "medium",
+                "queued_user_messages": 0,
+                "task_started_at": null,
+                "active_profile": null,
+                "indent_cols": null,
+            })
+        );
+
+        // Instants read back within the millisecond they were written at.
+        let restored: FooterProps =
+            serde_json::from_value(serde_json::to_value(&props).expect("serialize props"))
+                .expect("deserialize props");
+        let started_at = props.task_started_at.expect("start time");
+        let restored_at = restored.task_started_at.expect("restored start time");
+        let drift = restored_at.max(started_at) - restored_at.min(started_at);
+        assert!(drift < Duration::from_secs(1), "{drift:?}");
+    }
+
+    #[test]
+    fn transition_to_idle_waits_for_threshold() {
+        let threshold = Duration::from_secs(IDLE_TIMEOUT_SECS);
+        let last_activity = Instant::now();
+        let at = |offset| last_activity + offset;
+
+        let summary = FooterMode::ShortcutSummary;
+        let just_before = threshold - Duration::from_millis(1);
+        assert_eq!(
+            transition_to_idle(summary.clone(), last_activity, at(just_before), threshold),
+            FooterMode::ShortcutSummary
+        );
+        assert_eq!(
+            transition_to_idle(summary.clone(), last_activity, at(threshold), threshold),
+            FooterMode::Idle
+        );
+        // A clock reading from before the activity never counts as idle.
+        assert_eq!(
+            transition_to_idle(summary, at(threshold), last_activity, threshold),
+            FooterMode::ShortcutSummary
+        );
+        // Only the shortcut summary quiets down.
+        for mode in every_footer_mode() {
+            if mode != FooterMode::ShortcutSummary {
+                assert_eq!(
+                    transition_to_idle(mode.clone(), last_activity, at(threshold), threshold),
+                    mode
+                );
+            }
+        }
+        // Activity brings the summary back.
+        assert_eq!(
+            reset_mode_after_activity(FooterMode::Idle),
+            FooterMode::ShortcutSummary
+        );
+    }
+
+    #[test]
+    fn reconnecting_snapshots() {
+        // Pin the spinner's phase so the snapshots do not depend on timing.
+        with_animation_start(Instant::now(), || {
+            snapshot_footer(
+                "footer_reconnecting_first_attempt",
+                FooterProps::builder()
+                    .mode(FooterMode::Reconnecting(ReconnectState {
+                        attempt: 1,
+                        max_attempts: 5,
+                        next_retry_at: None,
+                    }))
+                    .task_running(true)
+                    .build(),
+            );
+            let now = Instant::now();
+            snapshot_footer_at(
+                "footer_reconnecting_countdown",
+                80,
+                FooterProps::builder()
+                    .mode(FooterMode::Reconnecting(ReconnectState {
+                        attempt: 2,
+                        max_attempts: 5,
+                        next_retry_at: Some(now + Duration::from_millis(2_900)),
+                    }))
+                    .task_running(true)
+                    .build(),
+                now,
+            );
+        });
+    }
+
+    #[test]
+    fn resuming_from_paused_returns_to_summary() {
+        assert_eq!(
+            reset_mode_after_activity(FooterMode::Paused),
+            FooterMode::ShortcutSummary
+        );
+    }
+
+    #[test]
+    fn progress_snapshots() {
+        for percent in [0, 50, 100] {
+            let props = FooterProps::builder()
+                .mode(FooterMode::Progress {
+                    label: "Indexing workspace".into(),
+                    percent,
+                })
+                .build();
+            assert_eq!(footer_height(&props, 80), 1);
+            snapshot_footer(&format!("footer_progress_{percent}_percent"), props);
+        }
+    }
+
+    #[test]
+    fn progress_clamps_and_clears_after_activity() {
+        assert_eq!(
+            progress_line("Copying", 250).to_string(),
+            format!("Copying {} 100%", "▓".repeat(PROGRESS_BAR_WIDTH))
+        );
+        assert_eq!(
+            reset_mode_after_activity(FooterMode::Progress {
+                label: "Copying".into(),
+                percent: 30,
+            }),
+            FooterMode::ShortcutSummary
+        );
+    }
+
+    #[test]
+    fn notification_survives_shortcut_toggle() {
+        let notification = FooterMode::Notification {
+            message: "careful".into(),
+            severity: NotificationSeverity::Warn,
+            expires_at: None,
+        };
+        assert_eq!(
+            toggle_shortcut_mode(notification.clone(), false, session_shortcuts(), 1),
+            notification
+        );
+        assert_eq!(
+            reset_mode_after_activity(notification),
+            FooterMode::ShortcutSummary
+        );
+    }
 }
@@ -1,4 +1,5 @@
 //! Bottom pane: shows the ChatComposer or a BottomPaneView, if one is active.
+use std::borrow::Cow;
 use std::path::PathBuf;
 
 use crate::app_event_sender::AppEventSender;
@@ -13,6 +14,7 @@ use ratatui::layout::Layout;
 use ratatui::layout::Rect;
 use ratatui::widgets::WidgetRef;
 use std::time::Duration;
+use std::time::Instant;
 
 mod approval_overlay;
 pub(crate) use approval_overlay::ApprovalOverlay;
@@ -24,6 +26,22 @@ mod command_popup;
 pub mod custom_prompt_view;
 mod file_search_popup;
 mod footer;
+pub(crate) use footer::FOOTER_NOTIFICATION_TIMEOUT;
+pub use footer::FooterMode;
+pub use footer::FooterProps;
+pub use footer::FooterPropsBuilder;
+pub use footer::KeymapStyle;
+pub use footer::NotificationSeverity;
+pub use footer::ReconnectState;
+pub use footer::SandboxBadge;
+pub(crate) use footer::ShortcutId;
+pub(crate) use footer::ShortcutTable;
+pub use footer::ShortcutsState;
+pub(crate) use footer::footer_height;
+pub(crate) use footer::render_footer;
+#[cfg(test)]
+pub(crate) use footer::render_footer_to_string;
+pub(crate) use footer::shortcut_reference_lines;
 mod list_selection_view;
 mod prompt_args;
 pub(crate) use list_selection_view::SelectionViewParams;
@@ -41,6 +59,9 @@ pub(crate) enum CancellationEvent {
 
 pub(crate) use chat_composer::ChatComposer;
 pub(crate) use chat_composer::InputResult;
+use codex_core::config_types::ComposerKeymap;
+use codex_core::config_types::FooterStyle;
+use codex_core::protocol_config_types::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::custom_prompts::CustomPrompt;
 
 use crate::status_indicator_widget::StatusIndicatorWidget;
@@ -69,6 +90,8 @@ pub(crate) struct BottomPane {
     /// Queued user messages to show under the status indicator.
     queued_user_messages: Vec<String>,
     context_window_percent: Option<u8>,
+    context_window_tokens: (Option<u64>, Option<u64>),
+    tokens_per_second: Option<f32>,
 }
 
 pub(crate) struct BottomPaneParams {
@@ -78,20 +101,31 @@ pub(crate) struct BottomPaneParams {
     pub(crate) enhanced_keys_supported: bool,
     pub(crate) placeholder_text: String,
     pub(crate) disable_paste_burst: bool,
+    pub(crate) footer_style: FooterStyle,
+    pub(crate) highlight_shortcut_keys: bool,
+    pub(crate) keymap: ComposerKeymap,
+    pub(crate) shortcut_table: ShortcutTable,
+    pub(crate) active_profile: Option<String>,
 }
 
 impl BottomPane {
     const BOTTOM_PAD_LINES: u16 = 0;
     pub fn new(params: BottomPaneParams) -> Self {
         let enhanced_keys_supported = params.enhanced_keys_supported;
+        let mut composer = ChatComposer::new(
+            params.has_input_focus,
+            params.app_event_tx.clone(),
+            enhanced_keys_supported,
+            params.placeholder_text,
+            params.disable_paste_burst,
+        );
+        composer.set_footer_style(params.footer_style);
+        composer.set_highlight_shortcut_keys(params.highlight_shortcut_keys);
+        composer.set_keymap(params.keymap);
+        composer.set_shortcut_table(params.shortcut_table);
+        composer.set_active_profile(params.active_profile);
         Self {
-            composer: ChatComposer::new(
-                params.has_input_focus,
-                params.app_event_tx.clone(),
-                enhanced_keys_supported,
-                params.placeholder_text,
-                params.disable_paste_burst,
-            ),
+            composer,
             view_stack: Vec::new(),
             app_event_tx: params.app_event_tx,
             frame_requester: params.frame_requester,
@@ -102,6 +136,8 @@ impl BottomPane {
             queued_user_messages: Vec::new(),
             esc_backtrack_hint: false,
             context_window_percent: None,
+            context_window_tokens: (None, None),
+            tokens_per_second: None,
         }
     }
 
@@ -124,7 +160,9 @@ impl BottomPane {
 
         // Base height depends on whether a modal/overlay is active.
         let base = match self.active_view().as_ref() {
-            Some(view) => view.desired_height(width),
+            Some(view) => view
+                .desired_height(width)
+                .saturating_add(self.view_footer_height()),
             None => self.composer.desired_height(width).saturating_add(
                 self.status
                     .as_ref()
@@ -136,6 +174,19 @@ impl BottomPane {
             .saturating_add(top_margin)
     }
 
+    /// Rows kept under an active view for the composer footer: one while an
+    /// approval is pending, so its keys stay on screen below the modal.
+    fn view_footer_height(&self) -> u16 {
+        u16::from(self.composer.is_awaiting_approval())
+    }
+
+    fn split_view_footer(&self, content: Rect) -> [Rect; 2] {
+        let footer_height = self
+            .view_footer_height()
+            .min(content.height.saturating_sub(1));
+        Layout::vertical([Constraint::Min(1), Constraint::Length(footer_height)]).areas(content)
+    }
+
     fn layout(&self, area: Rect) -> [Rect; 2] {
         // At small heights, bottom pane takes the entire height.
         let (top_margin, bottom_margin) = if area.height <= BottomPane::BOTTOM_PAD_LINES + 1 {
@@ -171,7 +222,8 @@ impl BottomPane {
         // show its caret.
         let [_, content] = self.layout(area);
         if let Some(view) = self.active_view() {
-            view.cursor_pos(content)
+            let [view_area, _] = self.split_view_footer(content);
+            view.cursor_pos(view_area)
         } else {
             self.composer.cursor_pos(content)
         }
@@ -306,6 +358,16 @@ impl BottomPane {
         }
     }
 
+    /// Whether a Ctrl+C at `now` is the second press of a double press. Once
+    /// the reminder's window has passed the hint is cleared, so quitting takes
+    /// a fresh pair of presses.
+    pub(crate) fn ctrl_c_quit_hint_active(&mut self, now: Instant) -> bool {
+        if self.ctrl_c_quit_hint && !self.composer.ctrl_c_reminder_active(now) {
+            self.clear_ctrl_c_quit_hint();
+        }
+        self.ctrl_c_quit_hint
+    }
+
     #[cfg(test)]
     pub(crate) fn ctrl_c_quit_hint_visible(&self) -> bool {
         self.ctrl_c_quit_hint
@@ -332,6 +394,7 @@ impl BottomPane {
         self.composer.set_task_running(running);
 
         if running {
+            self.composer.set_paused(false);
             if self.status.is_none() {
                 self.status = Some(StatusIndicatorWidget::new(
                     self.app_event_tx.clone(),
@@ -341,6 +404,7 @@ impl BottomPane {
             if let Some(status) = self.status.as_mut() {
                 status.set_queued_messages(self.queued_user_messages.clone());
             }
+            self.composer.set_esc_interrupts(true);
             self.request_redraw();
         } else {
             // Hide the status indicator when a task completes, but keep other modal views.
@@ -348,9 +412,16 @@ impl BottomPane {
         }
     }
 
+    /// Show "paused · enter to resume" until the next turn starts.
+    pub(crate) fn show_paused(&mut self) {
+        self.composer.set_paused(true);
+        self.request_redraw();
+    }
+
     /// Hide the status indicator while leaving task-running state untouched.
     pub(crate) fn hide_status_indicator(&mut self) {
         if self.status.take().is_some() {
+            self.composer.set_esc_interrupts(false);
             self.request_redraw();
         }
     }
@@ -365,6 +436,77 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Let the shortcut overlay offer to edit the previous message once the
+    /// transcript has one.
+    pub(crate) fn set_has_previous_message(&mut self, has_previous: bool) {
+        self.composer.set_has_previous_message(has_previous);
+        self.request_redraw();
+    }
+
+    /// Update the sandbox reminder shown at the start of the footer.
+    pub(crate) fn set_sandbox_badge(&mut self, badge: Option<SandboxBadge>) {
+        self.composer.set_sandbox_badge(badge);
+        self.request_redraw();
+    }
+
+    /// Update the model label shown on the right of the shortcut summary.
+    pub(crate) fn set_model_info(
+        &mut self,
+        model: &str,
+        reasoning_effort: Option<ReasoningEffortConfig>,
+    ) {
+        self.composer
+            .set_model_info(Some(model.to_string()), reasoning_effort);
+        self.request_redraw();
+    }
+
+    /// Update the absolute token usage shown next to the context percentage.
+    pub(crate) fn set_context_window_tokens(&mut self, used: Option<u64>, max: Option<u64>) {
+        if self.context_window_tokens == (used, max) {
+            return;
+        }
+
+        self.context_window_tokens = (used, max);
+        self.composer.set_context_window_tokens(used, max);
+        self.request_redraw();
+    }
+
+    /// Show a short notification in the footer for `ttl`, then fall back to
+    /// the regular shortcut summary. The follow-up redraw lets the footer
+    /// notice the expiry even when nothing else is happening.
+    pub(crate) fn show_footer_notification(
+        &mut self,
+        message: impl Into<Cow<'static, str>>,
+        severity: NotificationSeverity,
+        ttl: Duration,
+    ) {
+        self.composer
+            .show_footer_notification(message.into(), severity, ttl);
+        self.request_redraw();
+        self.request_redraw_in(ttl);
+    }
+
+    /// Update the token throughput shown in the footer while a task runs.
+    pub(crate) fn set_tokens_per_second(&mut self, rate: Option<f32>) {
+        if self.tokens_per_second == rate {
+            return;
+        }
+
+        self.tokens_per_second = rate;
+        self.composer.set_tokens_per_second(rate);
+        self.request_redraw();
+    }
+
+    /// Show (or with `None`, clear) the reconnect progress in the footer.
+    pub(crate) fn set_reconnecting(&mut self, state: Option<ReconnectState>) {
+        if state.is_none() && !self.composer.is_reconnecting() {
+            return;
+        }
+
+        self.composer.set_reconnecting(state);
+        self.request_redraw();
+    }
+
     /// Show a generic list selection view with the provided items.
     pub(crate) fn show_selection_view(&mut self, params: list_selection_view::SelectionViewParams) {
         let view = list_selection_view::ListSelectionView::new(params, self.app_event_tx.clone());
@@ -373,6 +515,7 @@ impl BottomPane {
 
     /// Update the queued messages shown under the status header.
     pub(crate) fn set_queued_user_messages(&mut self, queued: Vec<String>) {
+        self.composer.set_queued_user_message_count(queued.len());
         self.queued_user_messages = queued.clone();
         if let Some(status) = self.status.as_mut() {
             status.set_queued_messages(queued);
@@ -386,6 +529,11 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Footer props for the shortcut overlay as it would show right now.
+    pub(crate) fn shortcut_overlay_props(&self) -> FooterProps {
+        self.composer.shortcut_overlay_props()
+    }
+
     pub(crate) fn composer_is_empty(&self) -> bool {
         self.composer.is_empty()
     }
@@ -422,11 +570,15 @@ impl BottomPane {
         // Otherwise create a new approval modal overlay.
         let modal = ApprovalOverlay::new(request, self.app_event_tx.clone());
         self.pause_status_timer_for_modal();
+        self.composer.set_awaiting_approval(true);
         self.push_view(Box::new(modal));
     }
 
     fn on_active_view_complete(&mut self) {
         self.resume_status_timer_after_modal();
+        if self.view_stack.is_empty() {
+            self.composer.set_awaiting_approval(false);
+        }
     }
 
     fn pause_status_timer_for_modal(&mut self) {
@@ -507,9 +659,14 @@ impl WidgetRef for &BottomPane {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         let [status_area, content] = self.layout(area);
 
-        // When a modal view is active, it owns the whole content area.
+        // When a modal view is active, it owns the content area, less the
+        // footer row kept for a pending approval.
         if let Some(view) = self.active_view() {
-            view.render(content, buf);
+            let [view_area, footer_area] = self.split_view_footer(content);
+            view.render(view_area, buf);
+            if !footer_area.is_empty() {
+                self.composer.render_footer_row(footer_area, buf);
+            }
         } else {
             // No active modal:
             // If a status indicator is active, render it above the composer.
@@ -519,6 +676,24 @@ impl WidgetRef for &BottomPane {
 
             // Render the composer in the remaining area.
             self.composer.render_ref(content, buf);
+
+            // The status indicator animates while visible; without it, keep the
+            // footer's elapsed time (tenths of a second at first) ticking.
+            if (self.is_task_running && self.status.is_none()) || self.composer.is_reconnecting() {
+                self.frame_requester
+                    .schedule_frame_in(Duration::from_millis(100));
+            }
+            // The Ctrl+C countdown redraws when its number changes and once
+            // more when the window closes, the same deadline `on_ctrl_c` checks.
+            let now = Instant::now();
+            if let Some(tick) = self.composer.ctrl_c_reminder_next_tick(now) {
+                self.frame_requester
+                    .schedule_frame_in(tick.saturating_duration_since(now));
+            }
+            if let Some(deadline) = self.composer.idle_deadline() {
+                self.frame_requester
+                    .schedule_frame_in(deadline.saturating_duration_since(Instant::now()));
+            }
         }
     }
 }
@@ -527,6 +702,7 @@ impl WidgetRef for &BottomPane {
 mod tests {
     use super::*;
     use crate::app_event::AppEvent;
+    use crate::shimmer::with_animation_start;
     use insta::assert_snapshot;
     use ratatui::buffer::Buffer;
     use ratatui::layout::Rect;
@@ -569,6 +745,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
         pane.push_approval_request(exec_request());
         assert_eq!(CancellationEvent::Handled, pane.on_ctrl_c());
@@ -576,6 +757,32 @@ mod tests {
         assert_eq!(CancellationEvent::NotHandled, pane.on_ctrl_c());
     }
 
+    #[test]
+    fn ctrl_c_quit_hint_clears_once_its_window_passes() {
+        let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx_raw);
+        let mut pane = BottomPane::new(BottomPaneParams {
+            app_event_tx: tx,
+            frame_requester: FrameRequester::test_dummy(),
+            has_input_focus: true,
+            enhanced_keys_supported: false,
+            placeholder_text: "Ask Codex to do anything".to_string(),
+            disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
+        });
+
+        pane.show_ctrl_c_quit_hint();
+        let armed_at = Instant::now();
+        assert!(pane.ctrl_c_quit_hint_active(armed_at));
+
+        assert!(!pane.ctrl_c_quit_hint_active(armed_at + footer::CTRL_C_REMINDER_TIMEOUT));
+        assert!(!pane.ctrl_c_quit_hint_visible());
+    }
+
     // live ring removed; related tests deleted.
 
     #[test]
@@ -589,6 +796,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
 
         // Create an approval modal (active view).
@@ -620,6 +832,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
 
         // Start a running task so the status indicator is active above the composer.
@@ -677,6 +894,46 @@ mod tests {
         drop(rx);
     }
 
+    #[test]
+    fn approval_footer_cleared_once_resolved() {
+        let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx_raw);
+        let mut pane = BottomPane::new(BottomPaneParams {
+            app_event_tx: tx,
+            frame_requester: FrameRequester::test_dummy(),
+            has_input_focus: true,
+            enhanced_keys_supported: false,
+            placeholder_text: "Ask Codex to do anything".to_string(),
+            disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            active_profile: None,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+        });
+
+        pane.push_approval_request(exec_request());
+        let height = pane.desired_height(60);
+        let rendered = render_snapshot(&pane, Rect::new(0, 0, 60, height));
+        assert!(
+            rendered.contains("waiting for approval"),
+            "expected the approval footer under the modal: {rendered:?}"
+        );
+
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+        pane.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(pane.view_stack.is_empty());
+
+        let rendered = render_snapshot(&pane, Rect::new(0, 0, 60, 4));
+        assert!(
+            rendered.contains("? for shortcuts"),
+            "expected the shortcut summary after approval: {rendered:?}"
+        );
+        assert!(!rendered.contains("waiting for approval"));
+    }
+
     #[test]
     fn status_indicator_visible_during_command_execution() {
         let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
@@ -688,6 +945,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
 
         // Begin a task: show initial status.
@@ -719,6 +981,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
 
         // Activate spinner (status view replaces composer) with no live ring.
@@ -733,7 +1000,7 @@ mod tests {
         let area = Rect::new(0, 0, 30, height);
         assert_snapshot!(
             "status_and_composer_fill_height_without_bottom_padding",
-            render_snapshot(&pane, area)
+            with_animation_start(Instant::now(), || render_snapshot(&pane, area))
         );
     }
 
@@ -748,6 +1015,11 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            footer_style: FooterStyle::Full,
+            highlight_shortcut_keys: false,
+            keymap: ComposerKeymap::Emacs,
+            shortcut_table: ShortcutTable::default(),
+            active_profile: None,
         });
 
         pane.set_task_running(true);
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use codex_core::config::Config;
 use codex_core::config_types::Notifications;
@@ -34,6 +36,7 @@ use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::StreamErrorEvent;
+use codex_core::protocol::StreamRetry;
 use codex_core::protocol::TaskCompleteEvent;
 use codex_core::protocol::TokenUsage;
 use codex_core::protocol::TokenUsageInfo;
@@ -70,10 +73,16 @@ use crate::bottom_pane::ApprovalRequest;
 use crate::bottom_pane::BottomPane;
 use crate::bottom_pane::BottomPaneParams;
 use crate::bottom_pane::CancellationEvent;
+use crate::bottom_pane::FOOTER_NOTIFICATION_TIMEOUT;
 use crate::bottom_pane::InputResult;
+use crate::bottom_pane::NotificationSeverity;
+use crate::bottom_pane::ReconnectState;
+use crate::bottom_pane::SandboxBadge;
 use crate::bottom_pane::SelectionAction;
 use crate::bottom_pane::SelectionItem;
 use crate::bottom_pane::SelectionViewParams;
+use crate::bottom_pane::ShortcutId;
+use crate::bottom_pane::ShortcutTable;
 use crate::bottom_pane::custom_prompt_view::CustomPromptView;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
 use crate::clipboard_paste::paste_image_to_temp_png;
@@ -132,6 +141,54 @@ struct RunningCommand {
 
 const RATE_LIMIT_WARNING_THRESHOLDS: [f64; 3] = [75.0, 90.0, 95.0];
 
+/// How far back the footer's tokens/sec looks, so the rate follows what the
+/// model is producing now rather than averaging over the whole turn.
+const TOKEN_RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// Output token totals reported during the running task, for a tokens/sec
+/// rate over the last `TOKEN_RATE_WINDOW`.
+#[derive(Default)]
+struct TokenRateWindow {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl TokenRateWindow {
+    /// Starts tracking a task whose output token total is `output_tokens`
+    /// at `now`.
+    fn reset(&mut self, now: Instant, output_tokens: u64) {
+        self.samples.clear();
+        self.samples.push_back((now, output_tokens));
+    }
+
+    fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Records the running total `output_tokens` at `now` and returns the
+    /// rate since the oldest total still inside the window. Totals arrive in
+    /// steps, so when only the newest one is that recent the rate reaches back
+    /// to the one before it. `None` while no task is tracked or nothing was
+    /// produced.
+    fn record(&mut self, now: Instant, output_tokens: u64) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        self.samples.push_back((now, output_tokens));
+        while self.samples.len() > 2
+            && self
+                .samples
+                .front()
+                .is_some_and(|&(at, _)| now.saturating_duration_since(at) > TOKEN_RATE_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+        let &(since, baseline) = self.samples.front()?;
+        let elapsed = now.saturating_duration_since(since).as_secs_f32();
+        let produced = output_tokens.saturating_sub(baseline);
+        (elapsed > 0.0 && produced > 0).then(|| produced as f32 / elapsed)
+    }
+}
+
 #[derive(Default)]
 struct RateLimitWarningState {
     secondary_index: usize,
@@ -236,6 +293,9 @@ pub(crate) struct ChatWidget {
     session_header: SessionHeader,
     initial_user_message: Option<UserMessage>,
     token_info: Option<TokenUsageInfo>,
+    // Recent output token totals of the running task, for the footer's
+    // tokens-per-second rate.
+    token_rate: TokenRateWindow,
     rate_limit_snapshot: Option<RateLimitSnapshotDisplay>,
     rate_limit_warnings: RateLimitWarningState,
     // Stream lifecycle controller
@@ -331,6 +391,10 @@ impl ChatWidget {
         let initial_messages = event.initial_messages.clone();
         let model_for_header = event.model.clone();
         self.session_header.set_model(&model_for_header);
+        self.bottom_pane
+            .set_model_info(&model_for_header, event.reasoning_effort);
+        self.bottom_pane
+            .set_sandbox_badge(Some(sandbox_badge(&self.config.sandbox_policy)));
         self.add_to_history(history_cell::new_session_info(
             &self.config,
             event,
@@ -406,6 +470,12 @@ impl ChatWidget {
     fn on_task_started(&mut self) {
         self.bottom_pane.clear_ctrl_c_quit_hint();
         self.bottom_pane.set_task_running(true);
+        let output_tokens = self
+            .token_info
+            .as_ref()
+            .map_or(0, |info| info.total_token_usage.output_tokens);
+        self.token_rate.reset(Instant::now(), output_tokens);
+        self.bottom_pane.set_tokens_per_second(None);
         self.retry_status_header = None;
         self.set_status_header(String::from("Working"));
         self.full_reasoning_buffer.clear();
@@ -418,6 +488,7 @@ impl ChatWidget {
         self.flush_answer_stream_with_separator();
         // Mark task stopped and request redraw now that all content is in history.
         self.bottom_pane.set_task_running(false);
+        self.clear_token_rate();
         self.running_commands.clear();
         self.request_redraw();
 
@@ -439,10 +510,25 @@ impl ChatWidget {
                     .percent_of_context_window_remaining(window)
             });
             self.bottom_pane.set_context_window_percent(percent);
+            self.bottom_pane.set_context_window_tokens(
+                Some(info.last_token_usage.tokens_in_context_window()),
+                context_window,
+            );
+            if let Some(rate) = self
+                .token_rate
+                .record(Instant::now(), info.total_token_usage.output_tokens)
+            {
+                self.bottom_pane.set_tokens_per_second(Some(rate));
+            }
             self.token_info = Some(info);
         }
     }
 
+    fn clear_token_rate(&mut self) {
+        self.token_rate.clear();
+        self.bottom_pane.set_tokens_per_second(None);
+    }
+
     fn on_rate_limit_snapshot(&mut self, snapshot: Option<RateLimitSnapshot>) {
         if let Some(snapshot) = snapshot {
             let warnings = self.rate_limit_warnings.take_warnings(
@@ -480,6 +566,7 @@ impl ChatWidget {
         self.finalize_active_cell_as_failed();
         // Reset running state and clear streaming buffers.
         self.bottom_pane.set_task_running(false);
+        self.clear_token_rate();
         self.running_commands.clear();
         self.stream_controller = None;
     }
@@ -505,6 +592,9 @@ impl ChatWidget {
                 "Conversation interrupted - tell the model what to do differently".to_owned(),
             ));
         }
+        if reason == TurnAbortReason::Interrupted {
+            self.bottom_pane.show_paused();
+        }
 
         // If any messages were queued during the task, restore them into the composer.
         if !self.queued_user_messages.is_empty() {
@@ -642,11 +732,18 @@ impl ChatWidget {
         debug!("BackgroundEvent: {message}");
     }
 
-    fn on_stream_error(&mut self, message: String) {
+    fn on_stream_error(&mut self, message: String, retry: Option<StreamRetry>) {
         if self.retry_status_header.is_none() {
             self.retry_status_header = Some(self.current_status_header.clone());
         }
         self.set_status_header(message);
+        if let Some(retry) = retry {
+            self.bottom_pane.set_reconnecting(Some(ReconnectState {
+                attempt: retry.attempt,
+                max_attempts: retry.max_attempts,
+                next_retry_at: Some(Instant::now() + Duration::from_millis(retry.delay_ms)),
+            }));
+        }
     }
 
     /// Periodic tick to commit at most one queued line to history with a small delay,
@@ -933,6 +1030,11 @@ impl ChatWidget {
                 enhanced_keys_supported,
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
+                footer_style: config.tui_footer,
+                highlight_shortcut_keys: config.tui_highlight_shortcut_keys,
+                keymap: config.tui_keymap,
+                shortcut_table: ShortcutTable::from_config(&config),
+                active_profile: config.active_profile.clone(),
             }),
             active_cell: None,
             config: config.clone(),
@@ -943,6 +1045,7 @@ impl ChatWidget {
                 initial_images,
             ),
             token_info: None,
+            token_rate: TokenRateWindow::default(),
             rate_limit_snapshot: None,
             rate_limit_warnings: RateLimitWarningState::default(),
             stream_controller: None,
@@ -998,6 +1101,11 @@ impl ChatWidget {
                 enhanced_keys_supported,
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
+                footer_style: config.tui_footer,
+                highlight_shortcut_keys: config.tui_highlight_shortcut_keys,
+                keymap: config.tui_keymap,
+                shortcut_table: ShortcutTable::from_config(&config),
+                active_profile: config.active_profile.clone(),
             }),
             active_cell: None,
             config: config.clone(),
@@ -1008,6 +1116,7 @@ impl ChatWidget {
                 initial_images,
             ),
             token_info: None,
+            token_rate: TokenRateWindow::default(),
             rate_limit_snapshot: None,
             rate_limit_warnings: RateLimitWarningState::default(),
             stream_controller: None,
@@ -1056,9 +1165,7 @@ impl ChatWidget {
                 kind: KeyEventKind::Press,
                 ..
             } if modifiers.contains(KeyModifiers::CONTROL) && c.eq_ignore_ascii_case(&'v') => {
-                if let Ok((path, info)) = paste_image_to_temp_png() {
-                    self.attach_image(path, info.width, info.height, info.encoded_format.label());
-                }
+                self.paste_image_from_clipboard();
                 return;
             }
             other if other.kind == KeyEventKind::Press => {
@@ -1120,6 +1227,39 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    fn paste_image_from_clipboard(&mut self) {
+        match paste_image_to_temp_png() {
+            Ok((path, info)) => {
+                self.attach_image(path, info.width, info.height, info.encoded_format.label());
+            }
+            Err(err) => {
+                tracing::warn!("failed to paste image: {err}");
+                self.bottom_pane.show_footer_notification(
+                    "Failed to paste image from clipboard",
+                    NotificationSeverity::Warn,
+                    FOOTER_NOTIFICATION_TIMEOUT,
+                );
+            }
+        }
+    }
+
+    /// Runs a shortcut picked from the overlay. The transcript and backtrack
+    /// shortcuts belong to the app and never reach here.
+    pub(crate) fn run_shortcut(&mut self, id: ShortcutId) {
+        match id {
+            ShortcutId::Commands => self.insert_str("/"),
+            ShortcutId::FilePaths => self.insert_str("@"),
+            ShortcutId::InsertNewline => self.insert_str("\n"),
+            ShortcutId::PasteImage => self.paste_image_from_clipboard(),
+            ShortcutId::Interrupt | ShortcutId::Quit => self.on_ctrl_c(),
+            ShortcutId::Compact => self.dispatch_command(SlashCommand::Compact),
+            ShortcutId::ClearContext => self.open_new_chat_confirmation(),
+            // Mode switches and app-level shortcuts have nothing to run here.
+            ShortcutId::VimModes | ShortcutId::EditPrevious | ShortcutId::ShowTranscript => {}
+        }
+        self.request_redraw();
+    }
+
     fn dispatch_command(&mut self, cmd: SlashCommand) {
         if !cmd.available_during_task() && self.bottom_pane.is_task_running() {
             let message = format!(
@@ -1194,6 +1334,11 @@ impl ChatWidget {
             SlashCommand::Status => {
                 self.add_status_output();
             }
+            SlashCommand::Shortcuts => {
+                self.add_to_history(history_cell::new_shortcuts_output(
+                    self.bottom_pane.shortcut_overlay_props(),
+                ));
+            }
             SlashCommand::Mcp => {
                 self.add_mcp_output();
             }
@@ -1316,6 +1461,7 @@ impl ChatWidget {
         // Only show the text portion in conversation history.
         if !text.is_empty() {
             self.add_to_history(history_cell::new_user_prompt(text));
+            self.bottom_pane.set_has_previous_message(true);
         }
         self.needs_final_message_separator = false;
     }
@@ -1406,6 +1552,12 @@ impl ChatWidget {
             }
         }
 
+        // Anything other than another retry notice means the stream is back
+        // or the turn is over, so the reconnect progress no longer applies.
+        if !matches!(msg, EventMsg::StreamError(_) | EventMsg::BackgroundEvent(_)) {
+            self.bottom_pane.set_reconnecting(None);
+        }
+
         match msg {
             EventMsg::SessionConfigured(e) => self.on_session_configured(e),
             EventMsg::AgentMessage(AgentMessageEvent { message }) => self.on_agent_message(message),
@@ -1468,7 +1620,9 @@ impl ChatWidget {
             EventMsg::BackgroundEvent(BackgroundEventEvent { message }) => {
                 self.on_background_event(message)
             }
-            EventMsg::StreamError(StreamErrorEvent { message }) => self.on_stream_error(message),
+            EventMsg::StreamError(StreamErrorEvent { message, retry }) => {
+                self.on_stream_error(message, retry)
+            }
             EventMsg::UserMessage(ev) => {
                 if from_replay {
                     self.on_user_message_event(ev);
@@ -1544,6 +1698,7 @@ impl ChatWidget {
                 let message = event.message.trim();
                 if !message.is_empty() {
                     self.add_to_history(history_cell::new_user_prompt(message.to_string()));
+                    self.bottom_pane.set_has_previous_message(true);
                 }
             }
         }
@@ -1942,6 +2097,38 @@ impl ChatWidget {
         });
     }
 
+    /// Asks before the shortcut overlay's "start a new chat" drops the
+    /// current conversation.
+    pub(crate) fn open_new_chat_confirmation(&mut self) {
+        if self.bottom_pane.is_task_running() {
+            // `/new` reports that it is disabled while a task is in progress.
+            self.dispatch_command(SlashCommand::New);
+            return;
+        }
+        let items = vec![
+            SelectionItem {
+                name: "Yes, start a new chat".to_string(),
+                description: Some("Drop this conversation and its context".to_string()),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::NewSession))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Cancel".to_string(),
+                description: Some("Keep working in this chat".to_string()),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Start a new chat?".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
     /// Set the approval policy in the widget's config copy.
     pub(crate) fn set_approval_policy(&mut self, policy: AskForApproval) {
         self.config.approval_policy = policy;
@@ -1949,6 +2136,8 @@ impl ChatWidget {
 
     /// Set the sandbox policy in the widget's config copy.
     pub(crate) fn set_sandbox_policy(&mut self, policy: SandboxPolicy) {
+        self.bottom_pane
+            .set_sandbox_badge(Some(sandbox_badge(&policy)));
         self.config.sandbox_policy = policy;
     }
 
@@ -1959,12 +2148,15 @@ impl ChatWidget {
     /// Set the reasoning effort in the widget's config copy.
     pub(crate) fn set_reasoning_effort(&mut self, effort: Option<ReasoningEffortConfig>) {
         self.config.model_reasoning_effort = effort;
+        self.bottom_pane.set_model_info(&self.config.model, effort);
     }
 
     /// Set the model in the widget's config copy.
     pub(crate) fn set_model(&mut self, model: &str) {
         self.session_header.set_model(model);
         self.config.model = model.to_string();
+        self.bottom_pane
+            .set_model_info(model, self.config.model_reasoning_effort);
     }
 
     pub(crate) fn add_info_message(&mut self, message: String, hint: Option<String>) {
@@ -2002,7 +2194,13 @@ impl ChatWidget {
             return;
         }
 
-        self.submit_op(Op::Shutdown);
+        // Quit only on the second press within the reminder's window; a late
+        // press arms the reminder again.
+        if self.bottom_pane.ctrl_c_quit_hint_active(Instant::now()) {
+            self.submit_op(Op::Shutdown);
+        } else {
+            self.bottom_pane.show_ctrl_c_quit_hint();
+        }
     }
 
     pub(crate) fn composer_is_empty(&self) -> bool {
@@ -2347,6 +2545,15 @@ const EXAMPLE_PROMPTS: [&str; 6] = [
     "Improve documentation in @filename",
 ];
 
+// Footer badge for the sandbox policy, using the names of the `/approvals` presets.
+fn sandbox_badge(policy: &SandboxPolicy) -> SandboxBadge {
+    match policy {
+        SandboxPolicy::ReadOnly => SandboxBadge::ReadOnly,
+        SandboxPolicy::WorkspaceWrite { .. } => SandboxBadge::Auto,
+        SandboxPolicy::DangerFullAccess => SandboxBadge::FullAccess,
+    }
+}
+
 // Extract the first bold (Markdown) element in the form **...** from `s`.
 // Returns the inner text if found; otherwise `None`.
 fn extract_first_bold(s: &str) -> Option<String> {
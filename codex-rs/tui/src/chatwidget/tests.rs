@@ -1,6 +1,7 @@
 use super::*;
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
+use crate::shimmer::with_animation_start;
 use crate::test_backend::VT100Backend;
 use crate::tui::FrameRequester;
 use assert_matches::assert_matches;
@@ -11,6 +12,8 @@ use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_core::config::ConfigToml;
 use codex_core::config::OPENAI_DEFAULT_MODEL;
+use codex_core::config_types::ComposerKeymap;
+use codex_core::config_types::FooterStyle;
 use codex_core::protocol::AgentMessageDeltaEvent;
 use codex_core::protocol::AgentMessageEvent;
 use codex_core::protocol::AgentReasoningDeltaEvent;
@@ -258,6 +261,11 @@ fn make_chatwidget_manual() -> (
         enhanced_keys_supported: false,
         placeholder_text: "Ask Codex to do anything".to_string(),
         disable_paste_burst: false,
+        footer_style: FooterStyle::Full,
+        highlight_shortcut_keys: false,
+        keymap: ComposerKeymap::Emacs,
+        shortcut_table: ShortcutTable::default(),
+        active_profile: None,
     });
     let auth_manager = AuthManager::from_auth_for_testing(CodexAuth::from_api_key("test"));
     let widget = ChatWidget {
@@ -270,6 +278,7 @@ fn make_chatwidget_manual() -> (
         session_header: SessionHeader::new(cfg.model),
         initial_user_message: None,
         token_info: None,
+        token_rate: TokenRateWindow::default(),
         rate_limit_snapshot: None,
         rate_limit_warnings: RateLimitWarningState::default(),
         stream_controller: None,
@@ -333,6 +342,25 @@ fn lines_to_single_string(lines: &[ratatui::text::Line<'static>]) -> String {
     s
 }
 
+#[test]
+fn token_rate_follows_the_last_few_seconds() {
+    let start = Instant::now();
+    let at = |secs| start + Duration::from_secs(secs);
+    let mut window = TokenRateWindow::default();
+    assert_eq!(window.record(at(1), 100), None, "no task is tracked");
+
+    window.reset(start, 1_000);
+    assert_eq!(window.record(at(10), 1_100), Some(10.0));
+    // A burst shows at full speed instead of being averaged with the slow
+    // start of the turn.
+    assert_eq!(window.record(at(12), 1_700), Some(300.0));
+    // Long after the burst only the last step counts.
+    assert_eq!(window.record(at(30), 1_880), Some(10.0));
+
+    window.clear();
+    assert_eq!(window.record(at(31), 2_000), None);
+}
+
 #[test]
 fn rate_limit_warnings_emit_thresholds() {
     let mut state = RateLimitWarningState::default();
@@ -543,11 +571,9 @@ fn end_exec(chat: &mut ChatWidget, call_id: &str, stdout: &str, stderr: &str, ex
 }
 
 fn active_blob(chat: &ChatWidget) -> String {
-    let lines = chat
-        .active_cell
-        .as_ref()
-        .expect("active cell present")
-        .display_lines(80);
+    let cell = chat.active_cell.as_ref().expect("active cell present");
+    // Pin the spinner's phase so snapshots do not depend on timing.
+    let lines = with_animation_start(Instant::now(), || cell.display_lines(80));
     lines_to_single_string(&lines)
 }
 
@@ -682,6 +708,11 @@ fn streaming_final_answer_keeps_task_running_state() {
 fn ctrl_c_shutdown_ignores_caps_lock() {
     let (mut chat, _rx, mut op_rx) = make_chatwidget_manual();
 
+    // The first press only arms the quit reminder.
+    chat.handle_key_event(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::CONTROL));
+    assert_matches!(op_rx.try_recv(), Err(TryRecvError::Empty));
+    assert!(chat.bottom_pane.ctrl_c_quit_hint_visible());
+
     chat.handle_key_event(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::CONTROL));
 
     match op_rx.try_recv() {
@@ -771,6 +802,61 @@ fn review_popup_custom_prompt_action_sends_event() {
     assert!(found, "expected OpenReviewCustomPrompt event to be sent");
 }
 
+#[test]
+fn slash_shortcuts_prints_the_overlay_into_history() {
+    use crate::bottom_pane::render_footer_to_string;
+
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
+    // Low context adds the compact entry, which the table must pick up too.
+    chat.bottom_pane.set_context_window_percent(Some(8));
+    chat.dispatch_command(SlashCommand::Shortcuts);
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1, "expected the shortcuts table");
+    let transcript: Vec<String> = lines_to_single_string(&cells[0])
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect();
+
+    // The overlay for the same state, drawn at the width history used.
+    let overlay = render_footer_to_string(80, chat.bottom_pane.shortcut_overlay_props());
+
+    assert_eq!(transcript[..2], ["/shortcuts", ""]);
+    assert_eq!(transcript[2..], overlay[..]);
+    assert!(
+        transcript
+            .iter()
+            .any(|line| line.contains("/compact to free context")),
+        "{transcript:?}"
+    );
+}
+
+#[test]
+fn shortcut_overlay_lists_the_configured_transcript_key() {
+    use crate::bottom_pane::render_footer_to_string;
+
+    let mut cfg = test_config();
+    cfg.tui_transcript_key = Some("ctrl+o".to_string());
+    let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
+    let pane = BottomPane::new(BottomPaneParams {
+        app_event_tx: AppEventSender::new(tx_raw),
+        frame_requester: FrameRequester::test_dummy(),
+        has_input_focus: true,
+        enhanced_keys_supported: false,
+        placeholder_text: "Ask Codex to do anything".to_string(),
+        disable_paste_burst: false,
+        footer_style: FooterStyle::Full,
+        highlight_shortcut_keys: false,
+        keymap: ComposerKeymap::Emacs,
+        shortcut_table: ShortcutTable::from_config(&cfg),
+        active_profile: None,
+    });
+
+    let overlay = render_footer_to_string(100, pane.shortcut_overlay_props()).join("\n");
+    assert!(overlay.contains("ctrl + o to view transcript"), "{overlay}");
+    assert!(!overlay.contains("ctrl + t"), "{overlay}");
+}
+
 #[test]
 fn slash_init_skips_when_project_doc_exists() {
     let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual();
@@ -1113,6 +1199,23 @@ fn full_access_confirmation_popup_snapshot() {
     assert_snapshot!("full_access_confirmation_popup", popup);
 }
 
+#[test]
+fn new_chat_shortcut_asks_before_starting_over() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual();
+
+    chat.run_shortcut(ShortcutId::ClearContext);
+    assert!(
+        !std::iter::from_fn(|| rx.try_recv().ok()).any(|ev| matches!(ev, AppEvent::NewSession)),
+        "the shortcut must not start a new chat before it is confirmed"
+    );
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert_snapshot!("new_chat_confirmation_popup", popup);
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert!(std::iter::from_fn(|| rx.try_recv().ok()).any(|ev| matches!(ev, AppEvent::NewSession)));
+}
+
 #[test]
 fn model_reasoning_selection_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual();
@@ -1555,6 +1658,49 @@ fn interrupt_prepends_queued_messages_before_existing_composer_text() {
     let _ = drain_insert_history(&mut rx);
 }
 
+#[test]
+fn enter_resumes_a_paused_turn() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual();
+    let footer_text = |chat: &ChatWidget| {
+        let width = 80;
+        let area = ratatui::layout::Rect::new(0, 0, width, chat.desired_height(width));
+        let mut buf = ratatui::buffer::Buffer::empty(area);
+        (&chat).render_ref(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    chat.bottom_pane.set_task_running(true);
+    chat.handle_codex_event(Event {
+        id: "turn-1".into(),
+        msg: EventMsg::TurnAborted(codex_core::protocol::TurnAbortedEvent {
+            reason: TurnAbortReason::Interrupted,
+        }),
+    });
+    let _ = drain_insert_history(&mut rx);
+    let text = footer_text(&chat);
+    assert!(text.contains("paused · enter to resume"), "{text}");
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    match op_rx.try_recv() {
+        Ok(Op::UserInput { items }) => assert_eq!(
+            items,
+            vec![InputItem::Text {
+                text: "continue".to_string()
+            }]
+        ),
+        other => panic!("expected the resume prompt, got {other:?}"),
+    }
+    let text = footer_text(&chat);
+    assert!(!text.contains("paused"), "{text}");
+}
+
 // Snapshot test: ChatWidget at very small heights (idle)
 // Ensures overall layout behaves when terminal height is extremely constrained.
 #[test]
@@ -1595,9 +1741,11 @@ fn ui_snapshots_small_heights_task_running() {
     for h in [1u16, 2, 3] {
         let name = format!("chat_small_running_h{h}");
         let mut terminal = Terminal::new(TestBackend::new(40, h)).expect("create terminal");
-        terminal
-            .draw(|f| f.render_widget_ref(&chat, f.area()))
-            .expect("draw chat running");
+        with_animation_start(Instant::now(), || {
+            terminal
+                .draw(|f| f.render_widget_ref(&chat, f.area()))
+                .expect("draw chat running")
+        });
         assert_snapshot!(name, terminal.backend());
     }
 }
@@ -1673,9 +1821,11 @@ fn status_widget_active_snapshot() {
     let height = chat.desired_height(80);
     let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, height))
         .expect("create terminal");
-    terminal
-        .draw(|f| f.render_widget_ref(&chat, f.area()))
-        .expect("draw status widget");
+    with_animation_start(Instant::now(), || {
+        terminal
+            .draw(|f| f.render_widget_ref(&chat, f.area()))
+            .expect("draw status widget")
+    });
     assert_snapshot!("status_widget_active", terminal.backend());
 }
 
@@ -2125,6 +2275,7 @@ fn stream_error_updates_status_indicator() {
         id: "sub-1".into(),
         msg: EventMsg::StreamError(StreamErrorEvent {
             message: msg.to_string(),
+            retry: None,
         }),
     });
 
@@ -2352,10 +2503,12 @@ fn chatwidget_exec_and_status_layout_vt100_snapshot() {
         crate::insert_history::insert_history_lines(&mut term, lines);
     }
 
-    term.draw(|f| {
-        (&chat).render_ref(f.area(), f.buffer_mut());
-    })
-    .unwrap();
+    with_animation_start(Instant::now(), || {
+        term.draw(|f| {
+            (&chat).render_ref(f.area(), f.buffer_mut());
+        })
+        .unwrap();
+    });
 
     assert_snapshot!(term.backend().vt100().screen().contents());
 }
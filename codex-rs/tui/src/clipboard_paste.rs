@@ -46,6 +46,10 @@ pub struct PastedImageInfo {
     pub encoded_format: EncodedImageFormat, // Always PNG for now.
 }
 
+/// Whether this build can read images from the clipboard. Fixed at compile
+/// time: false on Android, where `paste_image_as_png` always fails.
+pub const CLIPBOARD_IMAGES_SUPPORTED: bool = cfg!(not(target_os = "android"));
+
 /// Capture image from system clipboard, encode to PNG, and return bytes + info.
 #[cfg(not(target_os = "android"))]
 pub fn paste_image_as_png() -> Result<(Vec<u8>, PastedImageInfo), PasteImageError> {
@@ -10,3 +10,4 @@ pub(crate) use render::TOOL_CALL_MAX_LINES;
 pub(crate) use render::new_active_exec_command;
 pub(crate) use render::output_lines;
 pub(crate) use render::spinner;
+pub(crate) use render::spinner_at;
@@ -8,6 +8,7 @@ use crate::history_cell::HistoryCell;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
+use crate::shimmer::animation_start;
 use crate::shimmer::shimmer_spans;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_line;
@@ -141,8 +142,16 @@ pub(crate) fn output_lines(
     }
 }
 
-pub(crate) fn spinner(start_time: Option<Instant>) -> Span<'static> {
-    let elapsed = start_time.map(|st| st.elapsed()).unwrap_or_default();
+pub(crate) fn spinner() -> Span<'static> {
+    spinner_at(Instant::now())
+}
+
+/// [`spinner`] as of `now`, so callers drawing several time-dependent spans in
+/// one frame can share one clock read. The phase comes from the shared
+/// animation clock, not from when the work began, so every spinner on screen
+/// blinks together.
+pub(crate) fn spinner_at(now: Instant) -> Span<'static> {
+    let elapsed = now.saturating_duration_since(animation_start());
     if supports_color::on_cached(supports_color::Stream::Stdout)
         .map(|level| level.has_16m)
         .unwrap_or(false)
@@ -235,7 +244,7 @@ impl ExecCell {
         let mut out: Vec<Line<'static>> = Vec::new();
         out.push(Line::from(vec![
             if self.is_active() {
-                spinner(self.active_start_time())
+                spinner()
             } else {
                 "•".dim()
             },
@@ -343,7 +352,7 @@ impl ExecCell {
         let bullet = match success {
             Some(true) => "•".green().bold(),
             Some(false) => "•".red().bold(),
-            None => spinner(call.start_time),
+            None => spinner(),
         };
         let title = if self.is_active() { "Running" } else { "Ran" };
 
@@ -1,3 +1,5 @@
+use crate::bottom_pane::FooterProps;
+use crate::bottom_pane::shortcut_reference_lines;
 use crate::diff_render::create_diff_summary;
 use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
@@ -798,7 +800,7 @@ impl HistoryCell for McpToolCallCell {
         let bullet = match status {
             Some(true) => "•".green().bold(),
             Some(false) => "•".red().bold(),
-            None => spinner(Some(self.start_time)),
+            None => spinner(),
         };
         let header_text = if status.is_some() {
             "Called"
@@ -1078,6 +1080,32 @@ pub(crate) fn new_mcp_tools_output(
     PlainHistoryCell { lines }
 }
 
+/// `/shortcuts`: the shortcut overlay's table kept in the transcript. Like the
+/// overlay, it is laid out for the width it is drawn at.
+#[derive(Debug)]
+pub(crate) struct ShortcutsHistoryCell {
+    props: FooterProps,
+}
+
+impl HistoryCell for ShortcutsHistoryCell {
+    fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
+        const INDENT: &str = "  ";
+        let mut lines: Vec<Line<'static>> = vec!["/shortcuts".magenta().into(), "".into()];
+        let available = usize::from(width).saturating_sub(INDENT.len());
+        for mut line in shortcut_reference_lines(&self.props, available) {
+            line.spans.insert(0, INDENT.into());
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// `props` should be in `FooterMode::ShortcutOverlay`; see
+/// `BottomPane::shortcut_overlay_props`.
+pub(crate) fn new_shortcuts_output(props: FooterProps) -> ShortcutsHistoryCell {
+    ShortcutsHistoryCell { props }
+}
+
 pub(crate) fn new_info_event(message: String, hint: Option<String>) -> PlainHistoryCell {
     let mut line = vec!["• ".dim(), message.into()];
     if let Some(hint) = hint {
@@ -1308,6 +1336,7 @@ mod tests {
     use crate::exec_cell::CommandOutput;
     use crate::exec_cell::ExecCall;
     use crate::exec_cell::ExecCell;
+    use crate::shimmer::with_animation_start;
     use codex_core::config::Config;
     use codex_core::config::ConfigOverrides;
     use codex_core::config::ConfigToml;
@@ -1387,7 +1416,8 @@ mod tests {
         };
 
         let cell = new_active_mcp_tool_call("call-1".into(), invocation);
-        let rendered = render_lines(&cell.display_lines(80)).join("\n");
+        let lines = with_animation_start(Instant::now(), || cell.display_lines(80));
+        let rendered = render_lines(&lines).join("\n");
 
         insta::assert_snapshot!(rendered);
     }
@@ -1,14 +1,169 @@
+use codex_core::config_types::KeyHintModifiers;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
 use crossterm::event::KeyEventKind;
 use crossterm::event::KeyModifiers;
+use ratatui::style::Color;
 use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Span;
+#[cfg(test)]
+use std::cell::Cell;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
-const ALT_PREFIX: &str = "alt + ";
-const CTRL_PREFIX: &str = "ctrl + ";
-const SHIFT_PREFIX: &str = "shift + ";
+const ALT_PREFIX: &str = "alt";
+const CTRL_PREFIX: &str = "ctrl";
+const SHIFT_PREFIX: &str = "shift";
+
+/// macOS modifier symbols, in the order menus list them.
+const SYMBOLIC_MODIFIERS: [(KeyModifiers, &str); 4] = [
+    (KeyModifiers::CONTROL, "⌃"),
+    (KeyModifiers::ALT, "⌥"),
+    (KeyModifiers::SHIFT, "⇧"),
+    (KeyModifiers::SUPER, "⌘"),
+];
+
+/// Draw key hints without glyphs ("ctrl+c", "up") for fonts that lack them.
+/// Process-wide because every footer line and the overlay must agree.
+static ASCII_KEY_HINTS: AtomicBool = AtomicBool::new(false);
+
+/// Write modifiers as macOS symbols; chosen once at startup like
+/// `ASCII_KEY_HINTS`, which takes precedence over it.
+static SYMBOLIC_MODIFIERS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    static ASCII_KEY_HINTS_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+    static MODIFIER_STYLE_OVERRIDE: Cell<Option<KeyHintModifiers>> = const { Cell::new(None) };
+    static KEY_HINT_STYLE_OVERRIDE: Cell<Option<KeyHintStyle>> = const { Cell::new(None) };
+}
+
+pub(crate) fn set_ascii_key_hints(enabled: bool) {
+    ASCII_KEY_HINTS.store(enabled, Ordering::Relaxed);
+}
+
+fn ascii_key_hints() -> bool {
+    #[cfg(test)]
+    if let Some(enabled) = ASCII_KEY_HINTS_OVERRIDE.with(Cell::get) {
+        return enabled;
+    }
+    ASCII_KEY_HINTS.load(Ordering::Relaxed)
+}
+
+/// Runs `f` with ASCII key hints forced on or off for this thread only, so
+/// tests running in parallel do not see each other's setting.
+#[cfg(test)]
+pub(crate) fn with_ascii_key_hints<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    ASCII_KEY_HINTS_OVERRIDE.with(|active| active.set(Some(enabled)));
+    let result = f();
+    ASCII_KEY_HINTS_OVERRIDE.with(|active| active.set(None));
+    result
+}
+
+pub(crate) fn set_modifier_style(style: KeyHintModifiers) {
+    SYMBOLIC_MODIFIERS_ENABLED.store(style == KeyHintModifiers::Symbolic, Ordering::Relaxed);
+}
+
+fn modifier_style() -> KeyHintModifiers {
+    #[cfg(test)]
+    if let Some(style) = MODIFIER_STYLE_OVERRIDE.with(Cell::get) {
+        return style;
+    }
+    if SYMBOLIC_MODIFIERS_ENABLED.load(Ordering::Relaxed) {
+        KeyHintModifiers::Symbolic
+    } else {
+        KeyHintModifiers::Textual
+    }
+}
+
+/// Runs `f` with the modifier style forced for this thread only.
+#[cfg(test)]
+pub(crate) fn with_modifier_style<T>(style: KeyHintModifiers, f: impl FnOnce() -> T) -> T {
+    MODIFIER_STYLE_OVERRIDE.with(|active| active.set(Some(style)));
+    let result = f();
+    MODIFIER_STYLE_OVERRIDE.with(|active| active.set(None));
+    result
+}
+
+/// The modifier style users of this platform expect: symbols on macOS,
+/// words elsewhere.
+pub(crate) fn platform_modifier_style() -> KeyHintModifiers {
+    if cfg!(target_os = "macos") {
+        KeyHintModifiers::Symbolic
+    } else {
+        KeyHintModifiers::Textual
+    }
+}
+
+/// Whether `term` (the `TERM` variable) names a terminal whose usual fonts
+/// lack the arrow glyphs, e.g. the Linux console.
+pub(crate) fn term_lacks_glyphs(term: Option<&str>) -> bool {
+    matches!(term, Some("linux" | "vt100" | "vt220" | "dumb"))
+}
+
+/// How key hints such as "ctrl + c" are drawn. The default is the dim, bare
+/// hint used throughout the TUI.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct KeyHintStyle {
+    /// Wrap the hint in square brackets, e.g. "[ctrl + c]".
+    pub brackets: bool,
+    /// Draw the hint bold instead of dim.
+    pub bold: bool,
+    /// Foreground color for the hint.
+    pub fg: Option<Color>,
+}
+
+impl KeyHintStyle {
+    fn style(self) -> Style {
+        let style = if self.bold {
+            Style::default().bold()
+        } else {
+            Style::default().dim()
+        };
+        match self.fg {
+            Some(fg) => style.fg(fg),
+            None => style,
+        }
+    }
+}
+
+/// Style every key hint is drawn with; process-wide like `ASCII_KEY_HINTS`
+/// so hints agree whichever thread builds them.
+static ACTIVE_STYLE: RwLock<KeyHintStyle> = RwLock::new(KeyHintStyle {
+    brackets: false,
+    bold: false,
+    fg: None,
+});
+
+/// Sets the style key hints are drawn with. Call this once at startup; the
+/// footer caches its lines and will not pick up a change until its props do.
+pub fn set_key_hint_style(style: KeyHintStyle) {
+    *ACTIVE_STYLE
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = style;
+}
+
+pub(crate) fn key_hint_style() -> KeyHintStyle {
+    #[cfg(test)]
+    if let Some(style) = KEY_HINT_STYLE_OVERRIDE.with(Cell::get) {
+        return style;
+    }
+    *ACTIVE_STYLE
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Runs `f` with key hints drawn in `style` on this thread only, like
+/// `with_ascii_key_hints`.
+#[cfg(test)]
+pub(crate) fn with_key_hint_style<T>(style: KeyHintStyle, f: impl FnOnce() -> T) -> T {
+    KEY_HINT_STYLE_OVERRIDE.with(|active| active.set(Some(style)));
+    let result = f();
+    KEY_HINT_STYLE_OVERRIDE.with(|active| active.set(None));
+    result
+}
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) struct KeyBinding {
@@ -26,6 +181,56 @@ impl KeyBinding {
             && self.modifiers == event.modifiers
             && (event.kind == KeyEventKind::Press || event.kind == KeyEventKind::Repeat)
     }
+
+    /// This binding drawn with `style` rather than the active style.
+    pub(crate) const fn styled(self, style: KeyHintStyle) -> StyledKeyBinding {
+        StyledKeyBinding {
+            binding: self,
+            style,
+        }
+    }
+}
+
+/// A key binding, or two pressed one after the other such as "esc esc" or
+/// "g g". Drawn as the keys separated by a space.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct KeyChord {
+    first: KeyBinding,
+    then: Option<KeyBinding>,
+}
+
+impl KeyChord {
+    pub(crate) const fn single(binding: KeyBinding) -> Self {
+        Self {
+            first: binding,
+            then: None,
+        }
+    }
+
+    /// The key that starts the chord.
+    pub(crate) const fn first(&self) -> KeyBinding {
+        self.first
+    }
+}
+
+impl From<KeyBinding> for KeyChord {
+    fn from(binding: KeyBinding) -> Self {
+        Self::single(binding)
+    }
+}
+
+pub(crate) const fn chord(first: KeyBinding, then: KeyBinding) -> KeyChord {
+    KeyChord {
+        first,
+        then: Some(then),
+    }
+}
+
+/// A [`KeyBinding`] paired with the style to draw it in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct StyledKeyBinding {
+    binding: KeyBinding,
+    style: KeyHintStyle,
 }
 
 pub(crate) const fn plain(key: KeyCode) -> KeyBinding {
@@ -44,16 +249,73 @@ pub(crate) const fn ctrl(key: KeyCode) -> KeyBinding {
     KeyBinding::new(key, KeyModifiers::CONTROL)
 }
 
-fn modifiers_to_string(modifiers: KeyModifiers) -> String {
-    let mut result = String::new();
-    if modifiers.contains(KeyModifiers::CONTROL) {
-        result.push_str(CTRL_PREFIX);
-    }
-    if modifiers.contains(KeyModifiers::SHIFT) {
-        result.push_str(SHIFT_PREFIX);
+/// Opens the transcript unless the `tui.transcript_key` setting rebinds it.
+pub(crate) const DEFAULT_TRANSCRIPT_KEY: KeyBinding = ctrl(KeyCode::Char('t'));
+
+/// The key that opens the transcript: `spec`, the `tui.transcript_key`
+/// setting, when it names a key, otherwise [`DEFAULT_TRANSCRIPT_KEY`].
+pub(crate) fn transcript_key(spec: Option<&str>) -> KeyBinding {
+    spec.and_then(parse_key_binding)
+        .unwrap_or(DEFAULT_TRANSCRIPT_KEY)
+}
+
+/// Parses a key written the way ASCII hints draw it: modifiers joined to the
+/// key with `+`, such as "ctrl+o", "ctrl+shift+p", "alt+enter" or "f5".
+/// Case and spaces around `+` are ignored.
+pub(crate) fn parse_key_binding(spec: &str) -> Option<KeyBinding> {
+    let spec = spec.to_ascii_lowercase();
+    let mut parts: Vec<&str> = spec.split('+').map(str::trim).collect();
+    let key = parts.pop()?;
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        modifiers |= match part {
+            CTRL_PREFIX => KeyModifiers::CONTROL,
+            SHIFT_PREFIX => KeyModifiers::SHIFT,
+            ALT_PREFIX => KeyModifiers::ALT,
+            _ => return None,
+        };
     }
-    if modifiers.contains(KeyModifiers::ALT) {
-        result.push_str(ALT_PREFIX);
+    let code = match key {
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pgup" => KeyCode::PageUp,
+        "pgdn" => KeyCode::PageDown,
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next()?, chars.as_str()) {
+                ('f', number) if !number.is_empty() => KeyCode::F(number.parse().ok()?),
+                // Terminals report shifted letters in upper case.
+                (c, "") if modifiers.contains(KeyModifiers::SHIFT) => {
+                    KeyCode::Char(c.to_ascii_uppercase())
+                }
+                (c, "") => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyBinding::new(code, modifiers))
+}
+
+fn modifiers_to_string(modifiers: KeyModifiers, separator: &str) -> String {
+    let mut result = String::new();
+    for (modifier, prefix) in [
+        (KeyModifiers::CONTROL, CTRL_PREFIX),
+        (KeyModifiers::SHIFT, SHIFT_PREFIX),
+        (KeyModifiers::ALT, ALT_PREFIX),
+    ] {
+        if modifiers.contains(modifier) {
+            result.push_str(prefix);
+            result.push_str(separator);
+        }
     }
     result
 }
@@ -65,22 +327,198 @@ impl From<KeyBinding> for Span<'static> {
 }
 impl From<&KeyBinding> for Span<'static> {
     fn from(binding: &KeyBinding) -> Self {
-        let KeyBinding { key, modifiers } = binding;
-        let modifiers = modifiers_to_string(*modifiers);
+        binding.styled(key_hint_style()).into()
+    }
+}
+impl From<KeyChord> for Span<'static> {
+    fn from(chord: KeyChord) -> Self {
+        let first = Span::from(chord.first);
+        match chord.then {
+            Some(then) => {
+                let then = Span::from(then);
+                Span::styled(format!("{} {}", first.content, then.content), first.style)
+            }
+            None => first,
+        }
+    }
+}
+impl From<StyledKeyBinding> for Span<'static> {
+    fn from(styled: StyledKeyBinding) -> Self {
+        let StyledKeyBinding {
+            binding: KeyBinding { key, modifiers },
+            style,
+        } = styled;
+        let ascii = ascii_key_hints();
+        let symbolic = !ascii && modifier_style() == KeyHintModifiers::Symbolic;
+        let modifiers = if symbolic {
+            SYMBOLIC_MODIFIERS
+                .iter()
+                .filter(|(modifier, _)| modifiers.contains(*modifier))
+                .map(|(_, symbol)| *symbol)
+                .collect()
+        } else {
+            modifiers_to_string(modifiers, if ascii { "+" } else { " + " })
+        };
         let key = match key {
+            // Menus show "⌃T"; a bare letter such as vim's `i` stays as typed.
+            KeyCode::Char(c) if symbolic && !modifiers.is_empty() => {
+                c.to_ascii_uppercase().to_string()
+            }
             KeyCode::Enter => "enter".to_string(),
+            KeyCode::Up if ascii => "up".to_string(),
+            KeyCode::Down if ascii => "down".to_string(),
+            KeyCode::Left if ascii => "left".to_string(),
+            KeyCode::Right if ascii => "right".to_string(),
             KeyCode::Up => "↑".to_string(),
             KeyCode::Down => "↓".to_string(),
             KeyCode::Left => "←".to_string(),
             KeyCode::Right => "→".to_string(),
             KeyCode::PageUp => "pgup".to_string(),
             KeyCode::PageDown => "pgdn".to_string(),
+            KeyCode::F(n) => format!("f{n}"),
             _ => format!("{key}").to_ascii_lowercase(),
         };
-        Span::styled(format!("{modifiers}{key}"), key_hint_style())
+        let text = if style.brackets {
+            format!("[{modifiers}{key}]")
+        } else {
+            format!("{modifiers}{key}")
+        };
+        Span::styled(text, style.style())
     }
 }
 
-fn key_hint_style() -> Style {
-    Style::default().dim()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn custom_style_propagates_into_span() {
+        let branded = KeyHintStyle {
+            brackets: true,
+            bold: true,
+            fg: Some(Color::Cyan),
+        };
+        let expected = Span::styled("[ctrl + c]", Style::default().bold().fg(Color::Cyan));
+
+        assert_eq!(
+            Span::from(ctrl(KeyCode::Char('c')).styled(branded)),
+            expected
+        );
+
+        // The active style applies to plain conversions.
+        assert_eq!(
+            Span::from(ctrl(KeyCode::Char('c'))),
+            Span::styled("ctrl + c", Style::default().dim())
+        );
+        let styled = with_key_hint_style(branded, || Span::from(ctrl(KeyCode::Char('c'))));
+        assert_eq!(styled, expected);
+    }
+
+    #[test]
+    fn ascii_mode_drops_glyphs_and_spacing() {
+        let render = |binding: KeyBinding| Span::from(binding).content.into_owned();
+        let bindings = [
+            ctrl(KeyCode::Char('c')),
+            shift(KeyCode::Enter),
+            plain(KeyCode::Esc),
+            plain(KeyCode::Up),
+            alt(KeyCode::Left),
+        ];
+
+        let unicode: Vec<String> = bindings.into_iter().map(render).collect();
+        assert_eq!(
+            unicode,
+            ["ctrl + c", "shift + enter", "esc", "↑", "alt + ←"]
+        );
+
+        let ascii: Vec<String> =
+            with_ascii_key_hints(true, || bindings.into_iter().map(render).collect());
+        assert_eq!(ascii, ["ctrl+c", "shift+enter", "esc", "up", "alt+left"]);
+    }
+
+    #[test]
+    fn function_keys_and_combined_modifiers() {
+        let f1 = Span::from(plain(KeyCode::F(1)));
+        assert_eq!(f1.content, "f1");
+        assert_eq!(f1.width(), 2);
+
+        // Combined modifiers reach the hints through `tui.transcript_key`.
+        let rebound = transcript_key(Some("ctrl+shift+p"));
+        let palette = Span::from(rebound);
+        assert_eq!(palette.content, "ctrl + shift + p");
+        // Column layout measures spans by display width; plain text measures
+        // one column per byte.
+        assert_eq!(palette.width(), palette.content.len());
+
+        let ascii = with_ascii_key_hints(true, || Span::from(rebound));
+        assert_eq!(ascii.content, "ctrl+shift+p");
+    }
+
+    #[test]
+    fn symbolic_modifiers_use_macos_glyphs() {
+        let render = |binding: KeyBinding| Span::from(binding).content.into_owned();
+        let bindings = [
+            ctrl(KeyCode::Char('t')),
+            transcript_key(Some("ctrl+shift+p")),
+            alt(KeyCode::Up),
+            shift(KeyCode::Enter),
+            plain(KeyCode::Char('i')),
+        ];
+        let symbolic: Vec<String> = with_modifier_style(KeyHintModifiers::Symbolic, || {
+            bindings.into_iter().map(render).collect()
+        });
+        assert_eq!(symbolic, ["⌃T", "⌃⇧P", "⌥↑", "⇧enter", "i"]);
+
+        // ASCII mode wins, since the symbols are what it exists to avoid.
+        let ascii: Vec<String> = with_modifier_style(KeyHintModifiers::Symbolic, || {
+            with_ascii_key_hints(true, || bindings.into_iter().map(render).collect())
+        });
+        assert_eq!(
+            ascii,
+            ["ctrl+t", "ctrl+shift+p", "alt+up", "shift+enter", "i"]
+        );
+    }
+
+    #[test]
+    fn chords_render_keys_in_sequence() {
+        let single = Span::from(KeyChord::single(plain(KeyCode::Char('g'))));
+        assert_eq!(single.content, "g");
+        assert_eq!(single.width(), 1);
+
+        let top = Span::from(chord(plain(KeyCode::Char('g')), plain(KeyCode::Char('g'))));
+        assert_eq!(top, Span::styled("g g", Style::default().dim()));
+        assert_eq!(top.width(), 3);
+
+        let back = Span::from(chord(plain(KeyCode::Esc), plain(KeyCode::Esc)));
+        assert_eq!(back.content, "esc esc");
+        assert_eq!(back.width(), 7);
+
+        let mixed = Span::from(chord(ctrl(KeyCode::Char('x')), plain(KeyCode::Char('s'))));
+        assert_eq!(mixed.content, "ctrl + x s");
+        assert_eq!(mixed.width(), 10);
+    }
+
+    #[test]
+    fn parses_keys_as_hints_write_them() {
+        assert_eq!(parse_key_binding("ctrl+o"), Some(ctrl(KeyCode::Char('o'))));
+        assert_eq!(parse_key_binding("Alt + Enter"), Some(alt(KeyCode::Enter)));
+        assert_eq!(parse_key_binding("f5"), Some(plain(KeyCode::F(5))));
+        assert_eq!(parse_key_binding("f"), Some(plain(KeyCode::Char('f'))));
+        assert_eq!(parse_key_binding("ctrl+tea"), None);
+        assert_eq!(parse_key_binding("hyper+o"), None);
+        assert_eq!(parse_key_binding(""), None);
+
+        assert_eq!(transcript_key(None), DEFAULT_TRANSCRIPT_KEY);
+        assert_eq!(transcript_key(Some("ctrl+o")), ctrl(KeyCode::Char('o')));
+        assert_eq!(transcript_key(Some("ctrl+")), DEFAULT_TRANSCRIPT_KEY);
+    }
+
+    #[test]
+    fn ascii_detected_from_term() {
+        assert!(term_lacks_glyphs(Some("linux")));
+        assert!(term_lacks_glyphs(Some("dumb")));
+        assert!(!term_lacks_glyphs(Some("xterm-256color")));
+        assert!(!term_lacks_glyphs(None));
+    }
 }
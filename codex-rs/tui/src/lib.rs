@@ -116,6 +116,16 @@ pub use cli::Cli;
 pub use markdown_render::render_markdown_text;
 pub use public_widgets::composer_input::ComposerAction;
 pub use public_widgets::composer_input::ComposerInput;
+pub use public_widgets::footer::FooterMode;
+pub use public_widgets::footer::FooterProps;
+pub use public_widgets::footer::FooterPropsBuilder;
+pub use public_widgets::footer::FooterWidget;
+pub use public_widgets::footer::KeyHintStyle;
+pub use public_widgets::footer::KeymapStyle;
+pub use public_widgets::footer::NotificationSeverity;
+pub use public_widgets::footer::ReconnectState;
+pub use public_widgets::footer::SandboxBadge;
+pub use public_widgets::footer::ShortcutsState;
 use std::io::Write as _;
 
 // (tests access modules directly within the crate)
@@ -279,6 +289,21 @@ async fn run_ratatui_app(
         tracing::error!("panic: {info}");
         prev_hook(info);
     }));
+    key_hint::set_ascii_key_hints(
+        initial_config
+            .tui_ascii_key_hints
+            .unwrap_or_else(|| key_hint::term_lacks_glyphs(std::env::var("TERM").ok().as_deref())),
+    );
+    key_hint::set_modifier_style(
+        initial_config
+            .tui_key_hint_modifiers
+            .unwrap_or_else(key_hint::platform_modifier_style),
+    );
+    if let Some(spec) = initial_config.tui_transcript_key.as_deref()
+        && key_hint::parse_key_binding(spec).is_none()
+    {
+        tracing::warn!("ignoring tui.transcript_key {spec:?}: not a key");
+    }
     let mut terminal = tui::init()?;
     terminal.clear()?;
 
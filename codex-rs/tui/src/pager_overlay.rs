@@ -34,8 +34,13 @@ pub(crate) enum Overlay {
 }
 
 impl Overlay {
-    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>) -> Self {
-        Self::Transcript(TranscriptOverlay::new(cells))
+    /// The transcript pager; `toggle_key`, the key that opened it, closes it
+    /// again.
+    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>, toggle_key: KeyBinding) -> Self {
+        Self::Transcript(TranscriptOverlay {
+            toggle_key,
+            ..TranscriptOverlay::new(cells)
+        })
     }
 
     pub(crate) fn new_static_with_lines(lines: Vec<Line<'static>>, title: String) -> Self {
@@ -74,7 +79,6 @@ const KEY_END: KeyBinding = key_hint::plain(KeyCode::End);
 const KEY_Q: KeyBinding = key_hint::plain(KeyCode::Char('q'));
 const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
-const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
 const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
 
 // Common pager navigation hints rendered on the first line
@@ -374,6 +378,7 @@ pub(crate) struct TranscriptOverlay {
     view: PagerView,
     cells: Vec<Arc<dyn HistoryCell>>,
     highlight_cell: Option<usize>,
+    toggle_key: KeyBinding,
     is_done: bool,
 }
 
@@ -387,6 +392,7 @@ impl TranscriptOverlay {
             ),
             cells: transcript_cells,
             highlight_cell: None,
+            toggle_key: key_hint::DEFAULT_TRANSCRIPT_KEY,
             is_done: false,
         }
     }
@@ -470,7 +476,7 @@ impl TranscriptOverlay {
     pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
         match event {
             TuiEvent::Key(key_event) => match key_event {
-                e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) || KEY_CTRL_T.is_press(e) => {
+                e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) || self.toggle_key.is_press(e) => {
                     self.is_done = true;
                     Ok(())
                 }
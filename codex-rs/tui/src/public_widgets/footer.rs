@@ -0,0 +1,57 @@
+//! Public wrapper around the bottom pane footer so it can be embedded in other
+//! ratatui layouts or rendered in snapshot tests outside this crate.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use std::time::Instant;
+
+use crate::bottom_pane::footer_height;
+use crate::bottom_pane::render_footer;
+
+pub use crate::bottom_pane::FooterMode;
+pub use crate::bottom_pane::FooterProps;
+pub use crate::bottom_pane::FooterPropsBuilder;
+pub use crate::bottom_pane::KeymapStyle;
+pub use crate::bottom_pane::NotificationSeverity;
+pub use crate::bottom_pane::ReconnectState;
+pub use crate::bottom_pane::SandboxBadge;
+pub use crate::bottom_pane::ShortcutsState;
+pub use crate::key_hint::KeyHintStyle;
+pub use crate::key_hint::set_key_hint_style;
+
+/// Renders the footer line(s) shown under the chat composer.
+///
+/// Build the props with [`FooterProps::builder`] and size the area with
+/// [`FooterWidget::desired_height`], since the shortcut overlay spans several
+/// rows.
+#[derive(Clone, Debug)]
+pub struct FooterWidget {
+    props: FooterProps,
+    now: Option<Instant>,
+}
+
+impl FooterWidget {
+    pub fn new(props: FooterProps) -> Self {
+        Self { props, now: None }
+    }
+
+    /// Renders countdowns and elapsed times as of `now` instead of the time
+    /// the widget is drawn, e.g. to share one timestamp across a frame.
+    pub fn at(mut self, now: Instant) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Number of rows the footer occupies at `width`.
+    pub fn desired_height(&self, width: u16) -> u16 {
+        footer_height(&self.props, width)
+    }
+}
+
+impl Widget for FooterWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let now = self.now.unwrap_or_else(Instant::now);
+        render_footer(area, buf, &self.props, now);
+    }
+}
@@ -1 +1,2 @@
 pub mod composer_input;
+pub mod footer;
@@ -1,3 +1,4 @@
+use codex_ansi_escape::ansi_escape_line;
 use ratatui::text::Line;
 use ratatui::text::Span;
 
@@ -37,6 +38,8 @@ pub fn is_blank_line_spaces_only(line: &Line<'_>) -> bool {
 
 /// Prefix each line with `initial_prefix` for the first line and
 /// `subsequent_prefix` for following lines. Returns a new Vec of owned lines.
+/// Raw ANSI escape sequences in span contents are parsed into styles so they
+/// do not end up in buffer cells and shift the columns after them.
 pub fn prefix_lines(
     lines: Vec<Line<'static>>,
     initial_prefix: Span<'static>,
@@ -52,8 +55,73 @@ pub fn prefix_lines(
             } else {
                 subsequent_prefix.clone()
             });
-            spans.extend(l.spans);
+            spans.extend(l.spans.into_iter().flat_map(strip_ansi_span));
             Line::from(spans).style(l.style)
         })
         .collect()
 }
+
+fn strip_ansi_span(span: Span<'static>) -> Vec<Span<'static>> {
+    if !span.content.contains('\u{1b}') {
+        return vec![span];
+    }
+    ansi_escape_line(&span.content)
+        .spans
+        .into_iter()
+        .map(|parsed| Span {
+            style: span.style.patch(parsed.style),
+            content: parsed.content,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Stylize;
+    use ratatui::widgets::Paragraph;
+
+    fn render_rows(lines: Vec<Line<'static>>, width: u16) -> Vec<String> {
+        let height = lines.len() as u16;
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(Paragraph::new(lines), f.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prefix_lines_keeps_columns_with_styled_and_ansi_spans() {
+        let lines = vec![
+            Line::from(vec!["red".red(), " plain".into()]),
+            Line::from(vec![Span::from("\u{1b}[1mbold\u{1b}[0m"), " tail".dim()]),
+        ];
+        let prefixed = prefix_lines(lines, "› ".bold(), "  ".into());
+        let rows = render_rows(prefixed, 16);
+        assert_eq!(
+            rows,
+            vec![
+                "› red plain     ".to_string(),
+                "  bold tail     ".to_string(),
+            ]
+        );
+
+        // The escape codes become styling on the parsed span.
+        let prefixed = prefix_lines(
+            vec![Line::from("\u{1b}[1mbold\u{1b}[0m")],
+            "".into(),
+            "".into(),
+        );
+        assert_eq!(prefixed[0].spans[1..], [Span::from("bold").bold()]);
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(test)]
+use std::cell::Cell;
 use std::sync::OnceLock;
 use std::time::Duration;
 use std::time::Instant;
@@ -13,9 +15,34 @@ use crate::terminal_palette::default_fg;
 
 static PROCESS_START: OnceLock<Instant> = OnceLock::new();
 
+#[cfg(test)]
+thread_local! {
+    static ANIMATION_START_OVERRIDE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
 fn elapsed_since_start() -> Duration {
-    let start = PROCESS_START.get_or_init(Instant::now);
-    start.elapsed()
+    Instant::now().saturating_duration_since(animation_start())
+}
+
+/// Start of the shared animation clock. Animations take their phase from the
+/// time since this instant instead of from when their work began, so they
+/// move in step.
+pub(crate) fn animation_start() -> Instant {
+    #[cfg(test)]
+    if let Some(start) = ANIMATION_START_OVERRIDE.with(Cell::get) {
+        return start;
+    }
+    *PROCESS_START.get_or_init(Instant::now)
+}
+
+/// Run `f` with the animation clock started at `start` on this thread, for
+/// snapshots whose spinner phase must not depend on how long the run took.
+#[cfg(test)]
+pub(crate) fn with_animation_start<T>(start: Instant, f: impl FnOnce() -> T) -> T {
+    ANIMATION_START_OVERRIDE.with(|active| active.set(Some(start)));
+    let result = f();
+    ANIMATION_START_OVERRIDE.with(|active| active.set(None));
+    result
 }
 
 pub(crate) fn shimmer_spans(text: &str) -> Vec<Span<'static>> {
@@ -15,7 +15,7 @@ use ratatui::widgets::WidgetRef;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
-use crate::exec_cell::spinner;
+use crate::exec_cell::spinner_at;
 use crate::key_hint;
 use crate::shimmer::shimmer_spans;
 use crate::tui::FrameRequester;
@@ -172,7 +172,7 @@ impl WidgetRef for StatusIndicatorWidget {
 
         // Plain rendering: no borders or padding so the live cell is visually indistinguishable from terminal scrollback.
         let mut spans = Vec::with_capacity(5);
-        spans.push(spinner(Some(self.last_resume_at)));
+        spans.push(spinner_at(now));
         spans.push(" ".into());
         spans.extend(shimmer_spans(&self.header));
         spans.extend(vec![
@@ -222,6 +222,7 @@ mod tests {
     use super::*;
     use crate::app_event::AppEvent;
     use crate::app_event_sender::AppEventSender;
+    use crate::shimmer::with_animation_start;
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
     use std::time::Duration;
@@ -252,9 +253,11 @@ mod tests {
 
         // Render into a fixed-size test terminal and snapshot the backend.
         let mut terminal = Terminal::new(TestBackend::new(80, 2)).expect("terminal");
-        terminal
-            .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
-            .expect("draw");
+        with_animation_start(Instant::now(), || {
+            terminal
+                .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
+                .expect("draw")
+        });
         insta::assert_snapshot!(terminal.backend());
     }
 
@@ -266,9 +269,11 @@ mod tests {
 
         // Render into a fixed-size test terminal and snapshot the backend.
         let mut terminal = Terminal::new(TestBackend::new(20, 2)).expect("terminal");
-        terminal
-            .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
-            .expect("draw");
+        with_animation_start(Instant::now(), || {
+            terminal
+                .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
+                .expect("draw")
+        });
         insta::assert_snapshot!(terminal.backend());
     }
 
@@ -281,9 +286,11 @@ mod tests {
 
         // Render into a fixed-size test terminal and snapshot the backend.
         let mut terminal = Terminal::new(TestBackend::new(80, 8)).expect("terminal");
-        terminal
-            .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
-            .expect("draw");
+        with_animation_start(Instant::now(), || {
+            terminal
+                .draw(|f| w.render_ref(f.area(), f.buffer_mut()))
+                .expect("draw")
+        });
         insta::assert_snapshot!(terminal.backend());
     }
 
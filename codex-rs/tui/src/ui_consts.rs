@@ -9,3 +9,7 @@
 /// - User history lines account for this many columns (e.g., "▌ ") when wrapping.
 pub(crate) const LIVE_PREFIX_COLS: u16 = 2;
 pub(crate) const FOOTER_INDENT_COLS: usize = LIVE_PREFIX_COLS as usize;
+
+/// Seconds without input after which the footer's shortcut summary quiets down
+/// to the bare context indicator.
+pub(crate) const IDLE_TIMEOUT_SECS: u64 = 30;
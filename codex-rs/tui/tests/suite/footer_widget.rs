@@ -0,0 +1,53 @@
+//! The footer is exported as a standalone widget; render it through the public
+//! API to make sure the builder defaults and height reporting line up.
+
+use codex_tui::FooterMode;
+use codex_tui::FooterProps;
+use codex_tui::FooterWidget;
+use codex_tui::ShortcutsState;
+use pretty_assertions::assert_eq;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+
+fn render_rows(widget: FooterWidget, width: u16) -> Vec<String> {
+    let area = Rect::new(0, 0, width, widget.desired_height(width));
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn footer_widget_renders_builder_defaults() {
+    let widget = FooterWidget::new(FooterProps::builder().context_window_percent(72).build());
+    assert_eq!(
+        render_rows(widget, 60),
+        vec![format!(
+            "  ? for shortcuts{}72% context left",
+            " ".repeat(27)
+        )]
+    );
+}
+
+#[test]
+fn footer_widget_reports_overlay_height() {
+    let widget = FooterWidget::new(
+        FooterProps::builder()
+            .mode(FooterMode::ShortcutOverlay(ShortcutsState::default()))
+            .build(),
+    );
+    // A default state hides the paste-image and edit-previous entries, and
+    // hidden entries take no rows. Each group adds a header row, which takes
+    // the overlay past one page; the first page ends before the "Session"
+    // header, followed by the page indicator.
+    assert_eq!(widget.desired_height(40), 5);
+    assert_eq!(render_rows(widget, 40).len(), 5);
+}
@@ -1,4 +1,5 @@
 // Aggregates all former standalone integration tests as modules.
+mod footer_widget;
 mod status_indicator;
 mod vt100_history;
 mod vt100_live_commit;